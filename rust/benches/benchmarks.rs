@@ -0,0 +1,88 @@
+use clap::{command, value_parser, Arg, ArgAction};
+use project_euler::{
+    benchmark::{
+        bench_helpers, bench_solution, benchmark_report, record_benchmarks, regression_report,
+        BenchmarkOptions, BENCHMARK_HISTORY_FILE_PATH, DEFAULT_REGRESSION_THRESHOLD,
+    },
+    solutions::{get_solution, problem_indices},
+    OFFICIAL_PRESET,
+};
+
+/// Command-line argument benchmark name filter placeholder.
+const NAME_FILTER: &str = "NAME_FILTER";
+
+/// Command-line argument benchmark regression threshold placeholder.
+const REGRESSION_THRESHOLD: &str = "REGRESSION_THRESHOLD";
+
+/// Command-line argument pin benchmarks as baselines flag.
+const SET_BASELINE: &str = "set-baseline";
+
+/// Benchmark every solution using its official preset, followed by the shared helpers, print a
+/// report and record the benchmarks in the benchmark history file, reporting any regressions.
+fn main() {
+    let mut arg_matches = command!()
+        .ignore_errors(true)
+        .arg(Arg::new(NAME_FILTER).help("Text benchmark names must contain."))
+        .arg(
+            Arg::new(REGRESSION_THRESHOLD)
+                .long("regression-threshold")
+                .value_name(REGRESSION_THRESHOLD)
+                .value_parser(value_parser!(f64))
+                .help("Median time increase percentage reported as a regression."),
+        )
+        .arg(
+            Arg::new(SET_BASELINE)
+                .long(SET_BASELINE)
+                .action(ArgAction::SetTrue)
+                .help("Pin the benchmarks as the baselines later runs are compared against."),
+        )
+        .arg(
+            Arg::new("bench")
+                .long("bench")
+                .action(ArgAction::SetTrue)
+                .hide(true),
+        )
+        .get_matches();
+    let name_filter = arg_matches
+        .remove_one::<String>(NAME_FILTER)
+        .unwrap_or_default();
+    let regression_threshold = arg_matches
+        .remove_one::<f64>(REGRESSION_THRESHOLD)
+        .unwrap_or(DEFAULT_REGRESSION_THRESHOLD);
+    let set_baseline = arg_matches.get_flag(SET_BASELINE);
+    let options = BenchmarkOptions::default();
+
+    let mut benchmarks = vec![];
+
+    for problem_index in problem_indices() {
+        if !format!("solution_{:03}", problem_index).contains(&name_filter) {
+            continue;
+        }
+
+        let solution = get_solution(problem_index).expect("solution to be registered");
+
+        match bench_solution(problem_index, solution, OFFICIAL_PRESET, &options) {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(err) => eprintln!("Error in problem {}'s solution: {}", problem_index, err),
+        }
+    }
+
+    benchmarks.extend(bench_helpers(&name_filter, &options));
+
+    println!("{}", benchmark_report(&benchmarks));
+
+    match record_benchmarks(
+        BENCHMARK_HISTORY_FILE_PATH,
+        &benchmarks,
+        regression_threshold,
+        set_baseline,
+    ) {
+        Ok(regressions) if regressions.is_empty() => {}
+        Ok(regressions) => println!(
+            "\nRegressions of more than {}% found:\n\n{}",
+            regression_threshold,
+            regression_report(&regressions)
+        ),
+        Err(err) => eprintln!("\nFailed to record benchmarks: {}", err),
+    }
+}
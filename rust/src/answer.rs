@@ -0,0 +1,181 @@
+use num::{BigInt, BigRational, BigUint, ToPrimitive};
+use std::fmt::{self, Display};
+
+/// Represents a Project Euler problem solution answer.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    /// Integer answer.
+    Integer(i128),
+
+    /// Integer answer too large to fit in an `Integer`.
+    BigInteger(BigInt),
+
+    /// Text answer, e.g. a digit sequence or a word.
+    Text(String),
+
+    /// Exact fractional answer, displayed as `numerator/denominator`.
+    Fraction(BigRational),
+
+    /// Decimal answer rounded to a number of decimal places.
+    Decimal {
+        /// Decimal value.
+        value: f64,
+
+        /// Number of decimal places to round value to.
+        decimal_places: usize,
+    },
+}
+
+impl Answer {
+    /// Create decimal answer rounded to a number of decimal places.
+    /// - **value:** Decimal value.
+    /// - **decimal_places:** Number of decimal places to round value to.
+    pub fn decimal(value: f64, decimal_places: usize) -> Self {
+        Self::Decimal {
+            value,
+            decimal_places,
+        }
+    }
+
+    /// Get integer answer as a big integer, if answer is an integer.
+    fn to_big_integer(&self) -> Option<BigInt> {
+        match self {
+            Self::Integer(integer) => Some(BigInt::from(*integer)),
+            Self::BigInteger(big_integer) => Some(big_integer.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Integer(integer) => integer.fmt(formatter),
+            Self::BigInteger(big_integer) => big_integer.fmt(formatter),
+            Self::Text(text) => text.fmt(formatter),
+            Self::Fraction(fraction) => {
+                formatter.pad(&format!("{}/{}", fraction.numer(), fraction.denom()))
+            }
+            Self::Decimal {
+                value,
+                decimal_places,
+            } => formatter.pad(&format!("{:.*}", decimal_places, value)),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    /// Integer answers are compared by value regardless of their representation, and decimal
+    /// answers are compared by their rounded value.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Integer(_) | Self::BigInteger(_), Self::Integer(_) | Self::BigInteger(_)) => {
+                self.to_big_integer() == other.to_big_integer()
+            }
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::Fraction(a), Self::Fraction(b)) => a == b,
+            (Self::Decimal { .. }, Self::Decimal { .. }) => self.to_string() == other.to_string(),
+            _ => false,
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer_type:ty),*) => {
+        $(
+            impl From<$integer_type> for Answer {
+                fn from(integer: $integer_type) -> Self {
+                    Self::Integer(integer.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(integer: usize) -> Self {
+        Self::from(integer as u64)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(integer: u128) -> Self {
+        Self::from(BigInt::from(integer))
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(big_integer: BigInt) -> Self {
+        match big_integer.to_i128() {
+            Some(integer) => Self::Integer(integer),
+            None => Self::BigInteger(big_integer),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(big_integer: BigUint) -> Self {
+        Self::from(BigInt::from(big_integer))
+    }
+}
+
+impl From<BigRational> for Answer {
+    fn from(fraction: BigRational) -> Self {
+        Self::Fraction(fraction)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use num::{BigInt, BigRational, BigUint};
+
+    #[test]
+    fn answer_displays_each_representation() {
+        assert_eq!(Answer::from(-59_231).to_string(), "-59231");
+        assert_eq!(
+            Answer::from(BigUint::from(2_u32).pow(128)).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(Answer::from("0123456789").to_string(), "0123456789");
+        assert_eq!(
+            Answer::from(BigRational::new(BigInt::from(6), BigInt::from(8))).to_string(),
+            "3/4"
+        );
+        assert_eq!(Answer::decimal(0.1234567, 4).to_string(), "0.1235");
+    }
+
+    #[test]
+    fn answer_compares_integers_by_value() {
+        assert_eq!(
+            Answer::from(1_u64 << 40),
+            Answer::from(BigUint::from(1_u64 << 40))
+        );
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::BigInteger(BigInt::from(u64::MAX))
+        );
+        assert_ne!(Answer::from(27), Answer::from("27"));
+    }
+
+    #[test]
+    fn answer_compares_decimals_by_rounded_value() {
+        assert_eq!(Answer::decimal(2.000_001, 2), Answer::decimal(2.0, 2));
+        assert_ne!(Answer::decimal(2.01, 2), Answer::decimal(2.0, 2));
+    }
+}
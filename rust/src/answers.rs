@@ -0,0 +1,277 @@
+use crate::{Error, ProblemIndex, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+};
+
+/// Default known answers file path.
+pub const ANSWERS_FILE_PATH: &str = ".answers.dat";
+
+/// Prefix marking a stored answer as a SHA-256 hash of the answer.
+const HASH_PREFIX: &str = "sha256:";
+
+/// Result of comparing an answer against the known answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verification {
+    /// Answer matches the known answer.
+    Correct,
+
+    /// Answer does not match the known answer.
+    Wrong,
+
+    /// No known answer exists for the problem and arguments.
+    Unknown,
+}
+
+impl Display for Verification {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.pad(match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::Unknown => "unknown",
+        })
+    }
+}
+
+/// Known answer, stored either as plain text or as a hash to avoid spoiling it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum KnownAnswer {
+    /// Plain text answer.
+    Plain(String),
+
+    /// Lowercase hexadecimal SHA-256 hash of the answer.
+    Hashed(String),
+}
+
+impl KnownAnswer {
+    /// Check if answer matches the known answer.
+    /// - **answer:** Answer to check.
+    fn matches(&self, answer: &str) -> bool {
+        match self {
+            Self::Plain(known_answer) => known_answer == answer,
+            Self::Hashed(known_answer_hash) => *known_answer_hash == hash_answer(answer),
+        }
+    }
+}
+
+impl Display for KnownAnswer {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Plain(answer) => write!(formatter, "{}", answer),
+            Self::Hashed(answer_hash) => write!(formatter, "{}{}", HASH_PREFIX, answer_hash),
+        }
+    }
+}
+
+/// Store of known answers, keyed by problem index and solution input arguments.
+/// ## Notes
+/// - **File format:** One tab separated `PROBLEM_INDEX ARGUMENTS ANSWER` entry per line, where
+///   arguments are separated by spaces and the answer is either plain text or `sha256:` followed
+///   by the hexadecimal SHA-256 hash of the answer. Blank lines and lines starting with `#` are
+///   ignored.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    /// Known answers.
+    answers: HashMap<(ProblemIndex, String), KnownAnswer>,
+}
+
+impl AnswerStore {
+    /// Load answer store from file. A missing file results in an empty answer store.
+    /// - **file_path:** Answers file path.
+    pub fn load(file_path: &str) -> Result<Self> {
+        let file = match fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(Error::Io {
+                    file_path: file_path.to_string(),
+                    source,
+                })
+            }
+        };
+
+        let mut answer_store = Self::default();
+
+        for (line_index, line) in file.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid_entry = || Error::Parse {
+                file_path: file_path.to_string(),
+                line: line_index + 1,
+                column: 1,
+                message: "expected tab separated problem index, arguments and answer".to_string(),
+            };
+
+            let mut fields = line.split('\t');
+            let (problem_index, arguments, answer) =
+                match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                    (Some(problem_index), Some(arguments), Some(answer), None) => {
+                        (problem_index, arguments, answer)
+                    }
+                    _ => return Err(invalid_entry()),
+                };
+            let problem_index = problem_index.parse().map_err(|_| invalid_entry())?;
+
+            let known_answer = match answer.strip_prefix(HASH_PREFIX) {
+                Some(answer_hash) => KnownAnswer::Hashed(answer_hash.to_lowercase()),
+                None => KnownAnswer::Plain(answer.to_string()),
+            };
+
+            answer_store
+                .answers
+                .insert((problem_index, arguments.to_string()), known_answer);
+        }
+
+        Ok(answer_store)
+    }
+
+    /// Save answer store to file, ordered by problem index and arguments.
+    /// - **file_path:** Answers file path.
+    pub fn save(&self, file_path: &str) -> Result<()> {
+        let mut entries: Vec<_> = self.answers.iter().collect();
+        entries.sort_by_key(|(key, _)| *key);
+
+        let file: String = entries
+            .into_iter()
+            .map(|((problem_index, arguments), known_answer)| {
+                format!("{}\t{}\t{}\n", problem_index, arguments, known_answer)
+            })
+            .collect();
+
+        fs::write(file_path, file).map_err(|source| Error::Io {
+            file_path: file_path.to_string(),
+            source,
+        })
+    }
+
+    /// Add known answer, replacing any existing answer for the problem and arguments.
+    /// - **problem_index:** Problem index answer is for.
+    /// - **arguments:** Solution input arguments answer is for.
+    /// - **answer:** Known answer.
+    /// - **hashed:** Store answer hash instead of the plain text answer.
+    pub fn insert(
+        &mut self,
+        problem_index: ProblemIndex,
+        arguments: &[String],
+        answer: &str,
+        hashed: bool,
+    ) {
+        let known_answer = if hashed {
+            KnownAnswer::Hashed(hash_answer(answer))
+        } else {
+            KnownAnswer::Plain(answer.to_string())
+        };
+
+        self.answers
+            .insert((problem_index, arguments.join(" ")), known_answer);
+    }
+
+    /// Compare answer against the known answer.
+    /// - **problem_index:** Problem index answer is for.
+    /// - **arguments:** Solution input arguments used to get answer.
+    /// - **answer:** Answer to verify.
+    pub fn verify(
+        &self,
+        problem_index: ProblemIndex,
+        arguments: &[String],
+        answer: &str,
+    ) -> Verification {
+        match self.answers.get(&(problem_index, arguments.join(" "))) {
+            Some(known_answer) if known_answer.matches(answer) => Verification::Correct,
+            Some(_) => Verification::Wrong,
+            None => Verification::Unknown,
+        }
+    }
+}
+
+/// Get lowercase hexadecimal SHA-256 hash of answer.
+/// - **answer:** Answer to hash.
+fn hash_answer(answer: &str) -> String {
+    format!("{:x}", Sha256::digest(answer.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnswerStore, Verification};
+    use std::{env, fs};
+
+    static INPUT_FILE_PATH: &str = ".test_input/answers.dat";
+
+    fn arguments(arguments: &[&str]) -> Vec<String> {
+        arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn verify_compares_against_plain_and_hashed_answers() {
+        let answer_store = AnswerStore::load(INPUT_FILE_PATH).expect("answers file to be valid");
+
+        assert_eq!(
+            answer_store.verify(1, &arguments(&["10"]), "23"),
+            Verification::Correct
+        );
+        assert_eq!(
+            answer_store.verify(1, &arguments(&["10"]), "24"),
+            Verification::Wrong
+        );
+        assert_eq!(
+            answer_store.verify(8, &arguments(&[".test_input/008.dat", "2"]), "72"),
+            Verification::Correct
+        );
+        assert_eq!(
+            answer_store.verify(1, &arguments(&["20"]), "78"),
+            Verification::Unknown
+        );
+    }
+
+    #[test]
+    fn load_returns_empty_answer_store_for_missing_file() {
+        let answer_store =
+            AnswerStore::load(".test_input/missing.dat").expect("missing file to be allowed");
+
+        assert_eq!(
+            answer_store.verify(1, &arguments(&["10"]), "23"),
+            Verification::Unknown
+        );
+    }
+
+    #[test]
+    fn save_round_trips_inserted_answers() {
+        let file_path = env::temp_dir().join("project_euler_answers_round_trip.dat");
+        let file_path = file_path
+            .to_str()
+            .expect("temporary path to be valid UTF-8");
+
+        let mut answer_store = AnswerStore::default();
+        answer_store.insert(2, &arguments(&["100"]), "44", false);
+        answer_store.insert(3, &arguments(&["13195"]), "29", true);
+        answer_store
+            .save(file_path)
+            .expect("answers file to be saved");
+
+        let answer_store = AnswerStore::load(file_path).expect("answers file to be valid");
+        fs::remove_file(file_path).expect("answers file to be removed");
+
+        assert_eq!(
+            answer_store.verify(2, &arguments(&["100"]), "44"),
+            Verification::Correct
+        );
+        assert_eq!(
+            answer_store.verify(3, &arguments(&["13195"]), "29"),
+            Verification::Correct
+        );
+        assert_eq!(
+            answer_store.verify(3, &arguments(&["13195"]), "5"),
+            Verification::Wrong
+        );
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    answers::{AnswerStore, Verification},
+    parse_arguments, preset_arguments,
+    solutions::{get_solution, problem_indices},
+    table::format_table,
+    verify_answer, Answer, ProblemIndex, Solution,
+};
+use clap::ArgMatches;
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+/// Selection of problems to run solutions for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProblemSelection {
+    /// Every registered solution.
+    All,
+
+    /// A single problem, e.g. `11`.
+    Single(ProblemIndex),
+
+    /// An inclusive range of problems, e.g. `1-17`.
+    Range(ProblemIndex, ProblemIndex),
+
+    /// A comma separated list of problems and ranges, e.g. `3,7,11` or `1-4,6`.
+    List(Vec<ProblemIndex>),
+}
+
+impl ProblemSelection {
+    /// Get problem indices that make up the selection.
+    pub fn problem_indices(&self) -> Vec<ProblemIndex> {
+        match self {
+            Self::All => problem_indices().collect(),
+            Self::Single(problem_index) => vec![*problem_index],
+            Self::Range(start, end) => (*start..=*end).collect(),
+            Self::List(problem_indices) => problem_indices.clone(),
+        }
+    }
+}
+
+impl FromStr for ProblemSelection {
+    type Err = String;
+
+    fn from_str(selection: &str) -> Result<Self, Self::Err> {
+        fn parse_problem_index(problem_index: &str) -> Result<ProblemIndex, String> {
+            problem_index
+                .trim()
+                .parse()
+                .map_err(|_| format!("'{}' is not a valid problem number", problem_index))
+        }
+
+        let selection = selection.trim();
+
+        if selection.eq_ignore_ascii_case("all") {
+            Ok(Self::All)
+        } else if selection.contains(',') {
+            let mut problem_indices = vec![];
+
+            for list_item in selection.split(',') {
+                match list_item.parse()? {
+                    Self::All => return Err("'all' cannot be part of a list".to_string()),
+                    list_item => problem_indices.extend(list_item.problem_indices()),
+                }
+            }
+
+            Ok(Self::List(problem_indices))
+        } else if let Some((start, end)) = selection.split_once('-') {
+            let start = parse_problem_index(start)?;
+            let end = parse_problem_index(end)?;
+
+            if start > end {
+                return Err(format!("range start {} is after range end {}", start, end));
+            }
+
+            Ok(Self::Range(start, end))
+        } else {
+            parse_problem_index(selection).map(Self::Single)
+        }
+    }
+}
+
+/// Outcome of running a solution.
+#[derive(Debug, Eq, PartialEq)]
+pub enum RunStatus {
+    /// Solution ran to completion, with its answer compared against the known answer.
+    Completed(Verification),
+
+    /// Solution failed to parse its arguments, returned an error or panicked while running.
+    Failed(String),
+
+    /// No solution is registered for the problem.
+    NotImplemented,
+}
+
+impl RunStatus {
+    /// Check if solution ran to completion without its answer being wrong.
+    pub fn is_success(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed(Verification::Correct | Verification::Unknown)
+        )
+    }
+}
+
+impl Display for RunStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Completed(verification) => verification.fmt(formatter),
+            Self::Failed(_) => formatter.pad("failed"),
+            Self::NotImplemented => formatter.pad("not implemented"),
+        }
+    }
+}
+
+/// Result of running a solution.
+pub struct SolutionRun {
+    /// Problem index the solution was run for.
+    pub problem_index: ProblemIndex,
+
+    /// Name of the preset the solution was run with, if its arguments are those of a preset.
+    pub preset: Option<String>,
+
+    /// Solution input arguments the solution was run with.
+    pub arguments: Vec<String>,
+
+    /// Solution title.
+    pub title: String,
+
+    /// Solution answer, if the solution ran to completion.
+    pub answer: Option<Answer>,
+
+    /// Wall time taken to run the solution.
+    pub elapsed: Duration,
+
+    /// Run outcome.
+    pub status: RunStatus,
+}
+
+/// Run solutions for each passed in problem using the arguments of a preset.
+/// - **problem_indices:** Problem indices to run solutions for.
+/// - **preset_name:** Name of the preset to run solutions with.
+/// - **answer_store:** Known answers to verify solution answers against.
+pub fn run_solutions(
+    problem_indices: &[ProblemIndex],
+    preset_name: &str,
+    answer_store: &AnswerStore,
+) -> Vec<SolutionRun> {
+    problem_indices
+        .iter()
+        .map(|problem_index| run_preset_solution(*problem_index, preset_name, answer_store))
+        .collect()
+}
+
+/// Run solution for the passed in problem using the arguments of a preset.
+/// - **problem_index:** Problem index to run solution for.
+/// - **preset_name:** Name of the preset to run solution with.
+/// - **answer_store:** Known answers to verify solution answer against.
+fn run_preset_solution(
+    problem_index: ProblemIndex,
+    preset_name: &str,
+    answer_store: &AnswerStore,
+) -> SolutionRun {
+    let solution = match get_solution(problem_index) {
+        Some(solution) => solution,
+        None => {
+            return SolutionRun {
+                problem_index,
+                preset: Some(preset_name.to_string()),
+                arguments: vec![],
+                title: String::new(),
+                answer: None,
+                elapsed: Duration::ZERO,
+                status: RunStatus::NotImplemented,
+            }
+        }
+    };
+
+    let arguments = match preset_arguments(solution, preset_name) {
+        Ok(arguments) => arguments,
+        Err(err) => {
+            return SolutionRun {
+                problem_index,
+                preset: Some(preset_name.to_string()),
+                arguments: vec![],
+                title: solution.title(),
+                answer: None,
+                elapsed: Duration::ZERO,
+                status: RunStatus::Failed(err.to_string()),
+            }
+        }
+    };
+
+    match parse_arguments(solution, &arguments) {
+        Ok(arg_matches) => run_parsed_solution(
+            problem_index,
+            solution,
+            arguments,
+            &arg_matches,
+            answer_store,
+        ),
+        Err(err) => SolutionRun {
+            problem_index,
+            preset: Some(preset_name.to_string()),
+            arguments,
+            title: solution.title(),
+            answer: None,
+            elapsed: Duration::ZERO,
+            status: RunStatus::Failed(err.to_string()),
+        },
+    }
+}
+
+/// Run solution using parsed input arguments, timing it and verifying its answer.
+/// - **problem_index:** Problem index to run solution for.
+/// - **solution:** Solution to run.
+/// - **arguments:** Solution input arguments, without a binary name.
+/// - **arg_matches:** Parsed solution input arguments.
+/// - **answer_store:** Known answers to verify solution answer against.
+pub fn run_parsed_solution(
+    problem_index: ProblemIndex,
+    solution: &dyn Solution,
+    arguments: Vec<String>,
+    arg_matches: &ArgMatches,
+    answer_store: &AnswerStore,
+) -> SolutionRun {
+    let mut solution_run = SolutionRun {
+        problem_index,
+        preset: solution
+            .presets()
+            .into_iter()
+            .find(|preset| preset.has_arguments(&arguments))
+            .map(|preset| preset.name.to_string()),
+        arguments,
+        title: solution.title(),
+        answer: None,
+        elapsed: Duration::ZERO,
+        status: RunStatus::Failed(String::new()),
+    };
+
+    let start = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| solution.run(arg_matches)));
+    solution_run.elapsed = start.elapsed();
+
+    match answer {
+        Ok(Ok(answer)) => {
+            solution_run.status = RunStatus::Completed(verify_answer(
+                solution,
+                problem_index,
+                &solution_run.arguments,
+                &answer,
+                answer_store,
+            ));
+            solution_run.answer = Some(answer);
+        }
+        Ok(Err(err)) => solution_run.status = RunStatus::Failed(err.to_string()),
+        Err(_) => solution_run.status = RunStatus::Failed("solution panicked".to_string()),
+    }
+
+    solution_run
+}
+
+/// Get summary table of solution runs.
+/// - **solution_runs:** Solution runs to summarize.
+pub fn summary_table(solution_runs: &[SolutionRun]) -> String {
+    const HEADERS: [&str; 5] = ["Problem", "Title", "Answer", "Time", "Status"];
+
+    let rows: Vec<[String; 5]> = solution_runs
+        .iter()
+        .map(|solution_run| {
+            [
+                format!("{:03}", solution_run.problem_index),
+                solution_run.title.clone(),
+                solution_run
+                    .answer
+                    .as_ref()
+                    .map(|answer| answer.to_string())
+                    .unwrap_or_default(),
+                format!("{:.2?}", solution_run.elapsed),
+                solution_run.status.to_string(),
+            ]
+        })
+        .collect();
+
+    format_table(HEADERS, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_solutions, summary_table, ProblemSelection, RunStatus};
+    use crate::{
+        answers::{AnswerStore, Verification},
+        solutions::problem_indices,
+        EXAMPLE_PRESET, OFFICIAL_PRESET,
+    };
+
+    #[test]
+    fn problem_selection_parses_all() {
+        assert_eq!("all".parse(), Ok(ProblemSelection::All));
+        assert_eq!(
+            ProblemSelection::All.problem_indices(),
+            problem_indices().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn problem_selection_parses_single_problem() {
+        assert_eq!("11".parse(), Ok(ProblemSelection::Single(11)));
+    }
+
+    #[test]
+    fn problem_selection_parses_ranges() {
+        let selection: ProblemSelection = "3-6".parse().expect("range to be parsable");
+
+        assert_eq!(selection, ProblemSelection::Range(3, 6));
+        assert_eq!(selection.problem_indices(), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn problem_selection_parses_lists() {
+        let selection: ProblemSelection = "3,7,11".parse().expect("list to be parsable");
+        let selection_with_range: ProblemSelection = "1-3,7".parse().expect("list to be parsable");
+
+        assert_eq!(selection.problem_indices(), vec![3, 7, 11]);
+        assert_eq!(selection_with_range.problem_indices(), vec![1, 2, 3, 7]);
+    }
+
+    #[test]
+    fn problem_selection_rejects_malformed_selections() {
+        assert!("7-3".parse::<ProblemSelection>().is_err());
+        assert!("3,x".parse::<ProblemSelection>().is_err());
+        assert!("3,all".parse::<ProblemSelection>().is_err());
+        assert!("one".parse::<ProblemSelection>().is_err());
+    }
+
+    #[test]
+    fn run_solutions_reports_unregistered_problems() {
+        let solution_runs = run_solutions(&[1, 0], OFFICIAL_PRESET, &AnswerStore::default());
+
+        assert!(solution_runs[0].answer.is_some());
+        assert_eq!(
+            solution_runs[0].status,
+            RunStatus::Completed(Verification::Unknown)
+        );
+        assert_eq!(solution_runs[1].status, RunStatus::NotImplemented);
+    }
+
+    #[test]
+    fn run_solutions_verifies_answers_against_answer_store() {
+        let mut answer_store = AnswerStore::default();
+        answer_store.insert(6, &["100".to_string()], "0", false);
+
+        let solution_runs = run_solutions(&[6], OFFICIAL_PRESET, &answer_store);
+
+        assert_eq!(
+            solution_runs[0].status,
+            RunStatus::Completed(Verification::Wrong)
+        );
+    }
+
+    #[test]
+    fn run_solutions_verifies_example_answers_against_presets() {
+        let solution_runs = run_solutions(&[1, 13], EXAMPLE_PRESET, &AnswerStore::default());
+
+        assert!(
+            solution_runs
+                .iter()
+                .all(|solution_run| solution_run.status
+                    == RunStatus::Completed(Verification::Correct))
+        );
+    }
+
+    #[test]
+    fn run_solutions_reports_unknown_presets() {
+        let solution_runs = run_solutions(&[1], "unknown", &AnswerStore::default());
+
+        assert!(matches!(solution_runs[0].status, RunStatus::Failed(_)));
+    }
+
+    #[test]
+    fn summary_table_has_row_per_solution_run() {
+        let table = summary_table(&run_solutions(
+            &[1, 6],
+            OFFICIAL_PRESET,
+            &AnswerStore::default(),
+        ));
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Problem | Title"));
+        assert!(lines[2].starts_with("001     | Multiples of 3 or 5"));
+        assert!(lines[3].starts_with("006     | Sum square difference"));
+    }
+}
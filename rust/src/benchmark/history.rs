@@ -0,0 +1,499 @@
+use super::{Benchmark, Statistics};
+use crate::{table::format_table, Error, Result};
+use std::{
+    fs,
+    io::ErrorKind,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Default benchmark history file path.
+pub const BENCHMARK_HISTORY_FILE_PATH: &str = ".benchmark_history.dat";
+
+/// Default median time increase, as a percentage of the baseline median, reported as a regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+/// Git revision recorded when the current revision cannot be determined.
+const UNKNOWN_REVISION: &str = "unknown";
+
+/// Marker field of records pinned as the baseline of their benchmark.
+const BASELINE_MARKER: &str = "baseline";
+
+/// Recorded benchmark result.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkRecord {
+    /// Benchmark name, e.g. `solution_011` or a shared helper call.
+    pub name: String,
+
+    /// Name of the preset a solution was benchmarked with, if benchmarking a solution.
+    pub preset: Option<String>,
+
+    /// Git revision the benchmark was run at.
+    pub revision: String,
+
+    /// Time the benchmark was recorded at, in seconds since the Unix epoch.
+    pub timestamp: u64,
+
+    /// Summary statistics of the time taken by a single run of the benchmarked routine.
+    pub statistics: Statistics,
+
+    /// Whether the record was pinned as the baseline of its benchmark.
+    pub is_baseline: bool,
+}
+
+/// Benchmark whose median time got worse than its baseline by more than the regression threshold.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    /// Benchmark name.
+    pub name: String,
+
+    /// Name of the preset a solution was benchmarked with, if benchmarking a solution.
+    pub preset: Option<String>,
+
+    /// Git revision of the baseline.
+    pub baseline_revision: String,
+
+    /// Median time of the baseline.
+    pub baseline_median: Duration,
+
+    /// Median time of the benchmark.
+    pub median: Duration,
+
+    /// Median time increase, as a percentage of the baseline median.
+    pub increase: f64,
+}
+
+/// History of benchmark results, keyed by benchmark name, preset, git revision and timestamp.
+/// ## Notes
+/// - **File format:** One tab separated `NAME PRESET REVISION TIMESTAMP MEAN MEDIAN
+///   STANDARD_DEVIATION MIN MAX [baseline]` entry per line, where times are in nanoseconds, the
+///   preset is empty for benchmarks that are not of a solution and a trailing `baseline` field
+///   pins the record as the baseline of its benchmark. Blank lines and lines starting with `#` are
+///   ignored.
+#[derive(Debug, Default)]
+pub struct BenchmarkHistory {
+    /// Benchmark records, in the order they were recorded.
+    records: Vec<BenchmarkRecord>,
+}
+
+impl BenchmarkHistory {
+    /// Load benchmark history from file. A missing file results in an empty history.
+    /// - **file_path:** Benchmark history file path.
+    pub fn load(file_path: &str) -> Result<Self> {
+        let file = match fs::read_to_string(file_path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(Error::Io {
+                    file_path: file_path.to_string(),
+                    source,
+                })
+            }
+        };
+
+        let mut history = Self::default();
+
+        for (line_index, line) in file.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid_entry = || Error::Parse {
+                file_path: file_path.to_string(),
+                line: line_index + 1,
+                column: 1,
+                message: "expected tab separated name, preset, revision, timestamp and times"
+                    .to_string(),
+            };
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [name, preset, revision, timestamp, times @ ..] = fields.as_slice() else {
+                return Err(invalid_entry());
+            };
+            let (times, is_baseline) = match times {
+                [times @ .., marker] if *marker == BASELINE_MARKER => (times, true),
+                times => (times, false),
+            };
+            let times = times
+                .iter()
+                .map(|time| time.parse().map(Duration::from_nanos))
+                .collect::<std::result::Result<Vec<Duration>, _>>()
+                .map_err(|_| invalid_entry())?;
+            let [mean, median, standard_deviation, min, max] = times[..] else {
+                return Err(invalid_entry());
+            };
+
+            history.records.push(BenchmarkRecord {
+                name: name.to_string(),
+                preset: Some(preset.to_string()).filter(|preset| !preset.is_empty()),
+                revision: revision.to_string(),
+                timestamp: timestamp.parse().map_err(|_| invalid_entry())?,
+                statistics: Statistics {
+                    mean,
+                    median,
+                    standard_deviation,
+                    min,
+                    max,
+                },
+                is_baseline,
+            });
+        }
+
+        Ok(history)
+    }
+
+    /// Save benchmark history to file.
+    /// - **file_path:** Benchmark history file path.
+    pub fn save(&self, file_path: &str) -> Result<()> {
+        let file: String = self
+            .records
+            .iter()
+            .map(|record| {
+                let statistics = &record.statistics;
+
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}{}\n",
+                    record.name,
+                    record.preset.as_deref().unwrap_or_default(),
+                    record.revision,
+                    record.timestamp,
+                    statistics.mean.as_nanos(),
+                    statistics.median.as_nanos(),
+                    statistics.standard_deviation.as_nanos(),
+                    statistics.min.as_nanos(),
+                    statistics.max.as_nanos(),
+                    if record.is_baseline {
+                        format!("\t{}", BASELINE_MARKER)
+                    } else {
+                        String::new()
+                    }
+                )
+            })
+            .collect();
+
+        fs::write(file_path, file).map_err(|source| Error::Io {
+            file_path: file_path.to_string(),
+            source,
+        })
+    }
+
+    /// Get benchmark records, in the order they were recorded.
+    pub fn records(&self) -> &[BenchmarkRecord] {
+        &self.records
+    }
+
+    /// Get baseline of a benchmark, being its most recently pinned record, or else its record
+    /// with the best median time.
+    /// - **name:** Benchmark name.
+    /// - **preset:** Name of the preset a solution was benchmarked with, if benchmarking a solution.
+    /// ## Notes
+    /// - Later runs never replace the baseline unless pinned, so regressions keep being reported
+    ///   and slow creep across runs adds up against the best median.
+    /// - Records with a zero median, such as a trivially fast helper on a coarse clock, are not
+    ///   baselines, since no increase can be measured against them.
+    pub fn baseline(&self, name: &str, preset: Option<&str>) -> Option<&BenchmarkRecord> {
+        let records = || {
+            self.records.iter().filter(|record| {
+                record.name == name
+                    && record.preset.as_deref() == preset
+                    && !record.statistics.median.is_zero()
+            })
+        };
+
+        records()
+            .filter(|record| record.is_baseline)
+            .max_by_key(|record| record.timestamp)
+            .or_else(|| records().min_by_key(|record| (record.statistics.median, record.timestamp)))
+    }
+
+    /// Add benchmark results to history.
+    /// - **benchmarks:** Benchmarks to record.
+    /// - **revision:** Git revision the benchmarks were run at.
+    /// - **timestamp:** Time the benchmarks were run at, in seconds since the Unix epoch.
+    /// - **set_baseline:** Whether to pin the results as the baselines of their benchmarks.
+    pub fn record(
+        &mut self,
+        benchmarks: &[Benchmark],
+        revision: &str,
+        timestamp: u64,
+        set_baseline: bool,
+    ) {
+        self.records
+            .extend(benchmarks.iter().map(|benchmark| BenchmarkRecord {
+                name: benchmark.name.clone(),
+                preset: benchmark.preset.clone(),
+                revision: revision.to_string(),
+                timestamp,
+                statistics: benchmark.statistics,
+                is_baseline: set_baseline,
+            }));
+    }
+
+    /// Find benchmarks whose median time got worse than their baseline by more than a threshold.
+    /// - **benchmarks:** Benchmarks to compare against their baselines.
+    /// - **threshold:** Median time increase, as a percentage of the baseline median, above which
+    ///   a benchmark is reported as a regression.
+    pub fn find_regressions(&self, benchmarks: &[Benchmark], threshold: f64) -> Vec<Regression> {
+        benchmarks
+            .iter()
+            .filter_map(|benchmark| {
+                let baseline = self.baseline(&benchmark.name, benchmark.preset.as_deref())?;
+                let baseline_median = baseline.statistics.median;
+                if baseline_median.is_zero() {
+                    return None;
+                }
+
+                let median = benchmark.statistics.median;
+                let increase = (median.as_secs_f64() / baseline_median.as_secs_f64() - 1.0) * 100.0;
+
+                (increase > threshold).then(|| Regression {
+                    name: benchmark.name.clone(),
+                    preset: benchmark.preset.clone(),
+                    baseline_revision: baseline.revision.clone(),
+                    baseline_median,
+                    median,
+                    increase,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Compare benchmarks against their baselines in the benchmark history file, then record them in
+/// it at the current git revision and time.
+/// - **file_path:** Benchmark history file path.
+/// - **benchmarks:** Benchmarks to compare and record.
+/// - **threshold:** Median time increase, as a percentage of the baseline median, above which a
+///   benchmark is reported as a regression.
+/// - **set_baseline:** Whether to pin the benchmarks as the new baselines.
+pub fn record_benchmarks(
+    file_path: &str,
+    benchmarks: &[Benchmark],
+    threshold: f64,
+    set_baseline: bool,
+) -> Result<Vec<Regression>> {
+    let mut history = BenchmarkHistory::load(file_path)?;
+    let regressions = history.find_regressions(benchmarks, threshold);
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    history.record(benchmarks, &current_revision(), timestamp, set_baseline);
+    history.save(file_path)?;
+
+    Ok(regressions)
+}
+
+/// Get abbreviated git revision of the working directory, or `unknown` if it cannot be determined.
+pub fn current_revision() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|revision| revision.trim().to_string())
+        .filter(|revision| !revision.is_empty())
+        .unwrap_or_else(|| UNKNOWN_REVISION.to_string())
+}
+
+/// Get report table of regressions.
+/// - **regressions:** Regressions to report.
+pub fn regression_report(regressions: &[Regression]) -> String {
+    const HEADERS: [&str; 6] = [
+        "Benchmark",
+        "Preset",
+        "Baseline revision",
+        "Baseline median",
+        "Median",
+        "Increase",
+    ];
+
+    let rows: Vec<[String; 6]> = regressions
+        .iter()
+        .map(|regression| {
+            [
+                regression.name.clone(),
+                regression.preset.clone().unwrap_or_default(),
+                regression.baseline_revision.clone(),
+                format!("{:.2?}", regression.baseline_median),
+                format!("{:.2?}", regression.median),
+                format!("+{:.1}%", regression.increase),
+            ]
+        })
+        .collect();
+
+    format_table(HEADERS, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BenchmarkHistory;
+    use crate::benchmark::{Benchmark, Statistics};
+    use std::{env, fs, time::Duration};
+
+    static INPUT_FILE_PATH: &str = ".test_input/benchmark_history.dat";
+
+    fn benchmark(name: &str, preset: Option<&str>, median_nanos: u64) -> Benchmark {
+        let median = Duration::from_nanos(median_nanos);
+
+        Benchmark {
+            name: name.to_string(),
+            preset: preset.map(str::to_string),
+            samples: 1,
+            iterations: 1,
+            statistics: Statistics {
+                mean: median,
+                median,
+                standard_deviation: Duration::ZERO,
+                min: median,
+                max: median,
+            },
+        }
+    }
+
+    #[test]
+    fn baseline_is_best_median_of_benchmark_and_preset() {
+        let history = BenchmarkHistory::load(INPUT_FILE_PATH).expect("history file to be valid");
+
+        let baseline = history
+            .baseline("solution_014", Some("official"))
+            .expect("baseline to exist");
+        assert_eq!(baseline.revision, "a1b2c3d");
+        assert_eq!(baseline.statistics.median, Duration::from_millis(90));
+
+        let baseline = history
+            .baseline("to_english(u128::MAX)", None)
+            .expect("baseline to exist");
+        assert_eq!(baseline.statistics.median, Duration::from_nanos(700));
+
+        assert!(history.baseline("solution_014", Some("example")).is_none());
+    }
+
+    #[test]
+    fn find_regressions_flags_median_increases_above_threshold() {
+        let history = BenchmarkHistory::load(INPUT_FILE_PATH).expect("history file to be valid");
+
+        let regressions = history.find_regressions(
+            &[
+                benchmark("solution_014", Some("official"), 120_000_000),
+                benchmark("solution_014", Some("example"), 1_000_000),
+                benchmark("to_english(u128::MAX)", None, 650),
+            ],
+            10.0,
+        );
+
+        assert_eq!(regressions.len(), 1);
+
+        let regression = &regressions[0];
+        assert_eq!(regression.name, "solution_014");
+        assert_eq!(regression.preset.as_deref(), Some("official"));
+        assert_eq!(regression.baseline_revision, "a1b2c3d");
+        assert_eq!(regression.baseline_median, Duration::from_millis(90));
+        assert_eq!(regression.median, Duration::from_millis(120));
+        assert!((regression.increase - 100.0 / 3.0).abs() < 1e-9);
+        assert!(history
+            .find_regressions(
+                &[benchmark("solution_014", Some("official"), 120_000_000)],
+                35.0
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn regressions_do_not_replace_baseline() {
+        let mut history = BenchmarkHistory::default();
+        for (timestamp, median_nanos) in (1_700_000_000..).zip([1_000, 1_080, 1_160, 1_240]) {
+            let benchmarks = [benchmark("solution_001", Some("official"), median_nanos)];
+            let regressions = history.find_regressions(&benchmarks, 10.0);
+
+            assert_eq!(regressions.is_empty(), median_nanos < 1_100);
+            history.record(&benchmarks, "a1b2c3d", timestamp, false);
+        }
+
+        let baseline = history
+            .baseline("solution_001", Some("official"))
+            .expect("baseline to exist");
+        assert_eq!(baseline.statistics.median, Duration::from_nanos(1_000));
+    }
+
+    #[test]
+    fn pinned_baseline_takes_precedence() {
+        let mut history = BenchmarkHistory::default();
+        history.record(
+            &[benchmark("solution_001", Some("official"), 1_000)],
+            "a1b2c3d",
+            1_700_000_000,
+            false,
+        );
+        history.record(
+            &[benchmark("solution_001", Some("official"), 2_000)],
+            "b2c3d4e",
+            1_700_000_001,
+            true,
+        );
+        history.record(
+            &[benchmark("solution_001", Some("official"), 1_500)],
+            "c3d4e5f",
+            1_700_000_002,
+            false,
+        );
+
+        let baseline = history
+            .baseline("solution_001", Some("official"))
+            .expect("baseline to exist");
+        assert_eq!(baseline.revision, "b2c3d4e");
+        assert!(baseline.is_baseline);
+    }
+
+    #[test]
+    fn zero_median_records_are_not_baselines() {
+        let mut history = BenchmarkHistory::default();
+        history.record(
+            &[benchmark("is_palindrome(100 characters)", None, 0)],
+            "a1b2c3d",
+            1_700_000_000,
+            true,
+        );
+
+        assert!(history
+            .baseline("is_palindrome(100 characters)", None)
+            .is_none());
+        assert!(history
+            .find_regressions(&[benchmark("is_palindrome(100 characters)", None, 5)], 10.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn save_round_trips_recorded_benchmarks() {
+        let file_path = env::temp_dir().join("project_euler_benchmark_history_round_trip.dat");
+        let file_path = file_path
+            .to_str()
+            .expect("temporary path to be valid UTF-8");
+
+        let mut history = BenchmarkHistory::default();
+        history.record(
+            &[
+                benchmark("solution_001", Some("official"), 5_000),
+                benchmark("is_palindrome(100 characters)", None, 2_000),
+            ],
+            "a1b2c3d",
+            1_700_000_000,
+            false,
+        );
+        history.record(
+            &[benchmark("solution_001", Some("official"), 4_000)],
+            "b2c3d4e",
+            1_700_000_100,
+            true,
+        );
+        history.save(file_path).expect("history file to be saved");
+
+        let loaded_history = BenchmarkHistory::load(file_path).expect("history file to be valid");
+        fs::remove_file(file_path).expect("history file to be removed");
+
+        assert_eq!(loaded_history.records(), history.records());
+    }
+}
@@ -0,0 +1,404 @@
+mod history;
+
+pub use history::{
+    current_revision, record_benchmarks, regression_report, BenchmarkHistory, BenchmarkRecord,
+    Regression, BENCHMARK_HISTORY_FILE_PATH, DEFAULT_REGRESSION_THRESHOLD,
+};
+
+use crate::{
+    parse_arguments, preset_arguments,
+    shared::{
+        collatz::CollatzCache,
+        file_helpers::{read_to_grid, read_to_vector},
+        iterators::{CollatzSequence, FibonacciSequence, PrimeNumbers},
+        math_helpers::{
+            fibonacci::fibonacci_big,
+            is_prime_number, prime_factors, proper_divisors,
+            sieve::{count_primes_below, primes_below},
+        },
+        string_helpers::{is_palindrome, is_palindrome_in_base},
+        LinearRecurrence, ModInt, ToEnglish,
+    },
+    table::format_table,
+    ProblemIndex, Result, Solution,
+};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Delimited grid file used to benchmark the file helpers.
+const GRID_FILE_PATH: &str = ".test_input/file_helpers_4x4_grid_delimited.dat";
+
+/// Delimiter of the grid file used to benchmark the file helpers.
+const GRID_FILE_DELIMITER: char = '|';
+
+/// Shared helper benchmarks, as benchmark name and routine pairs.
+const HELPER_BENCHMARKS: &[(&str, fn())] = &[
+    ("is_prime_number(15_485_863)", || {
+        black_box(is_prime_number(black_box(15_485_863)));
+    }),
+    ("is_prime_number(18_446_744_073_709_551_557)", || {
+        black_box(is_prime_number(black_box(18_446_744_073_709_551_557)));
+    }),
+    ("prime_factors(15_485_863)", || {
+        black_box(prime_factors(black_box(15_485_863)));
+    }),
+    ("prime_factors(4_294_967_291 * 4_294_967_279)", || {
+        black_box(prime_factors(black_box(4_294_967_291 * 4_294_967_279)));
+    }),
+    ("proper_divisors(1_000_000)", || {
+        black_box(proper_divisors(black_box(1_000_000)));
+    }),
+    ("read_to_vector(4x4 delimited grid)", || {
+        black_box(read_to_vector::<u64>(
+            black_box(GRID_FILE_PATH),
+            Some(&GRID_FILE_DELIMITER),
+        ))
+        .expect("grid file to be valid");
+    }),
+    ("read_to_grid(4x4 delimited grid)", || {
+        black_box(read_to_grid(
+            black_box(GRID_FILE_PATH),
+            Some(&GRID_FILE_DELIMITER),
+        ))
+        .expect("grid file to be valid");
+    }),
+    ("is_palindrome(100 characters)", || {
+        black_box(is_palindrome(black_box(
+            "8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008",
+        )));
+    }),
+    (
+        "is_palindrome_in_base(9_999_999_999_999_999_999, 10)",
+        || {
+            black_box(is_palindrome_in_base(
+                black_box(9_999_999_999_999_999_999_u64),
+                10,
+            ));
+        },
+    ),
+    ("to_english(u128::MAX)", || {
+        black_box(black_box(u128::MAX).to_english());
+    }),
+    ("FibonacciSequence to 50th term", || {
+        black_box(FibonacciSequence::default().nth(black_box(49)));
+    }),
+    ("fibonacci_big(100_000)", || {
+        black_box(fibonacci_big(black_box(100_000)));
+    }),
+    ("LinearRecurrence tribonacci nth_mod(10^18)", || {
+        black_box(
+            LinearRecurrence::new(vec![1, 1, 1], vec![0, 0, 1])
+                .nth_mod(black_box(1_000_000_000_000_000_000), 1_000_000_007),
+        );
+    }),
+    ("PrimeNumbers to 1,000th prime", || {
+        black_box(PrimeNumbers::default().nth(black_box(999)));
+    }),
+    ("primes_below(2_000_000)", || {
+        black_box(primes_below(black_box(2_000_000)));
+    }),
+    ("count_primes_below(1_000_000)", || {
+        black_box(count_primes_below(black_box(1_000_000)));
+    }),
+    ("ModInt<1_000_000_007> product to 100,000", || {
+        black_box(
+            (1..=black_box(100_000))
+                .map(ModInt::<1_000_000_007>::new)
+                .product::<ModInt<1_000_000_007>>(),
+        );
+    }),
+    ("CollatzSequence from 1,000", || {
+        black_box(CollatzSequence::new(black_box(1_000)).count());
+    }),
+    ("CollatzCache longest chain below 100,000", || {
+        black_box(CollatzCache::new(100_000).longest_chain_below(black_box(100_000)));
+    }),
+];
+
+/// Benchmark warm-up, sampling and repetition settings.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchmarkOptions {
+    /// Time spent running the routine before sampling, to warm up caches and estimate its time.
+    pub warm_up: Duration,
+
+    /// Number of timed samples to collect.
+    pub samples: usize,
+
+    /// Target time of each sample, used to pick how many times the routine is repeated per sample.
+    pub sample_time: Duration,
+}
+
+impl Default for BenchmarkOptions {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(200),
+            samples: 20,
+            sample_time: Duration::from_millis(25),
+        }
+    }
+}
+
+/// Summary statistics of benchmark samples.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Statistics {
+    /// Mean time.
+    pub mean: Duration,
+
+    /// Median time.
+    pub median: Duration,
+
+    /// Sample standard deviation of the times.
+    pub standard_deviation: Duration,
+
+    /// Fastest time.
+    pub min: Duration,
+
+    /// Slowest time.
+    pub max: Duration,
+}
+
+impl Statistics {
+    /// Calculate summary statistics of samples, if there are any.
+    /// - **samples:** Sample times.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let sample_count = samples.len();
+        let middle = sample_count / 2;
+        let median = if sample_count.is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / sample_count as f64;
+        let variance = if sample_count > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (sample_count - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            standard_deviation: Duration::from_secs_f64(variance.sqrt()),
+            min: samples[0],
+            max: samples[sample_count - 1],
+        })
+    }
+}
+
+/// Result of benchmarking a routine.
+#[derive(Clone, Debug)]
+pub struct Benchmark {
+    /// Benchmark name, e.g. `solution_011` or a shared helper call.
+    pub name: String,
+
+    /// Name of the preset a solution was benchmarked with, if benchmarking a solution.
+    pub preset: Option<String>,
+
+    /// Number of timed samples collected.
+    pub samples: usize,
+
+    /// Number of times the routine was repeated per sample.
+    pub iterations: u64,
+
+    /// Summary statistics of the time taken by a single run of the routine.
+    pub statistics: Statistics,
+}
+
+/// Benchmark routine by warming it up, then timing repeated runs of it over a number of samples.
+/// - **name:** Benchmark name.
+/// - **options:** Benchmark warm-up, sampling and repetition settings.
+/// - **routine:** Routine to benchmark.
+pub fn bench<T>(
+    name: impl Into<String>,
+    options: &BenchmarkOptions,
+    mut routine: impl FnMut() -> T,
+) -> Benchmark {
+    let mut warm_up_iterations: u32 = 0;
+    let warm_up_start = Instant::now();
+    while warm_up_iterations == 0 || warm_up_start.elapsed() < options.warm_up {
+        black_box(routine());
+        warm_up_iterations += 1;
+    }
+    let estimated_time = warm_up_start.elapsed() / warm_up_iterations;
+
+    let iterations = (options.sample_time.as_nanos() / estimated_time.as_nanos().max(1))
+        .clamp(1, u32::MAX as u128) as u32;
+
+    let samples: Vec<Duration> = (0..options.samples.max(1))
+        .map(|_| {
+            let sample_start = Instant::now();
+            for _ in 0..iterations {
+                black_box(routine());
+            }
+
+            sample_start.elapsed() / iterations
+        })
+        .collect();
+
+    Benchmark {
+        name: name.into(),
+        preset: None,
+        samples: samples.len(),
+        iterations: iterations.into(),
+        statistics: Statistics::from_samples(&samples).expect("at least one sample to be taken"),
+    }
+}
+
+/// Benchmark solution using the arguments of a preset. Solution is run once beforehand, so a
+/// solution that returns an error is not benchmarked.
+/// - **problem_index:** Problem index of solution.
+/// - **solution:** Solution to benchmark.
+/// - **preset_name:** Name of the preset to benchmark solution with.
+/// - **options:** Benchmark warm-up, sampling and repetition settings.
+pub fn bench_solution(
+    problem_index: ProblemIndex,
+    solution: &dyn Solution,
+    preset_name: &str,
+    options: &BenchmarkOptions,
+) -> Result<Benchmark> {
+    let arguments = parse_arguments(solution, &preset_arguments(solution, preset_name)?)?;
+    solution.run(&arguments)?;
+
+    let mut benchmark = bench(format!("solution_{:03}", problem_index), options, || {
+        solution.run(&arguments)
+    });
+    benchmark.preset = Some(preset_name.to_string());
+
+    Ok(benchmark)
+}
+
+/// Benchmark shared helpers whose benchmark name contains a filter.
+/// - **name_filter:** Text benchmark names must contain, where empty text matches every helper.
+/// - **options:** Benchmark warm-up, sampling and repetition settings.
+pub fn bench_helpers(name_filter: &str, options: &BenchmarkOptions) -> Vec<Benchmark> {
+    HELPER_BENCHMARKS
+        .iter()
+        .filter(|(name, _)| name.contains(name_filter))
+        .map(|(name, routine)| bench(*name, options, routine))
+        .collect()
+}
+
+/// Get report table of benchmarks.
+/// - **benchmarks:** Benchmarks to report.
+pub fn benchmark_report(benchmarks: &[Benchmark]) -> String {
+    const HEADERS: [&str; 9] = [
+        "Benchmark",
+        "Preset",
+        "Samples",
+        "Iterations",
+        "Mean",
+        "Median",
+        "Std. dev.",
+        "Min",
+        "Max",
+    ];
+
+    let rows: Vec<[String; 9]> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let statistics = &benchmark.statistics;
+
+            [
+                benchmark.name.clone(),
+                benchmark.preset.clone().unwrap_or_default(),
+                benchmark.samples.to_string(),
+                benchmark.iterations.to_string(),
+                format!("{:.2?}", statistics.mean),
+                format!("{:.2?}", statistics.median),
+                format!("{:.2?}", statistics.standard_deviation),
+                format!("{:.2?}", statistics.min),
+                format!("{:.2?}", statistics.max),
+            ]
+        })
+        .collect();
+
+    format_table(HEADERS, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bench, bench_helpers, bench_solution, benchmark_report, BenchmarkOptions, Statistics,
+    };
+    use crate::{solutions::get_solution, EXAMPLE_PRESET};
+    use std::time::Duration;
+
+    static QUICK_OPTIONS: BenchmarkOptions = BenchmarkOptions {
+        warm_up: Duration::ZERO,
+        samples: 3,
+        sample_time: Duration::ZERO,
+    };
+
+    #[test]
+    fn statistics_summarizes_samples() {
+        let samples = [1, 10, 3, 2, 4].map(Duration::from_micros);
+        let statistics = Statistics::from_samples(&samples).expect("samples to be summarized");
+
+        assert_eq!(statistics.mean, Duration::from_micros(4));
+        assert_eq!(statistics.median, Duration::from_micros(3));
+        assert_eq!(statistics.min, Duration::from_micros(1));
+        assert_eq!(statistics.max, Duration::from_micros(10));
+        assert_eq!(
+            statistics.standard_deviation.as_nanos(),
+            Duration::from_secs_f64(12.5_f64.sqrt() / 1_000_000.0).as_nanos()
+        );
+    }
+
+    #[test]
+    fn statistics_uses_middle_average_as_median_of_even_samples() {
+        let samples = [4, 1, 2, 8].map(Duration::from_micros);
+        let statistics = Statistics::from_samples(&samples).expect("samples to be summarized");
+
+        assert_eq!(statistics.median, Duration::from_micros(3));
+        assert!(Statistics::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn bench_repeats_routine_for_each_sample() {
+        let mut runs = 0;
+        let benchmark = bench("count", &QUICK_OPTIONS, || runs += 1);
+
+        assert_eq!(benchmark.samples, 3);
+        assert_eq!(benchmark.iterations, 1);
+        assert_eq!(runs, 4);
+    }
+
+    #[test]
+    fn bench_solution_rejects_unknown_presets() {
+        let solution = get_solution(1).expect("solution to be registered");
+
+        assert!(bench_solution(1, solution, "unknown", &QUICK_OPTIONS).is_err());
+    }
+
+    #[test]
+    fn benchmark_report_has_row_per_benchmark() {
+        let solution = get_solution(1).expect("solution to be registered");
+        let mut benchmarks = bench_helpers("to_english", &QUICK_OPTIONS);
+        benchmarks.push(
+            bench_solution(1, solution, EXAMPLE_PRESET, &QUICK_OPTIONS)
+                .expect("solution to be benchmarked"),
+        );
+
+        let report = benchmark_report(&benchmarks);
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Benchmark "));
+        assert!(lines[2].starts_with("to_english(u128::MAX) |"));
+        assert!(lines[3].starts_with("solution_001          | example |"));
+    }
+}
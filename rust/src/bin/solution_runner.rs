@@ -1,80 +1,375 @@
-use clap::{command, value_parser, Arg};
-use project_euler::ProblemIndex;
-use std::{
-    env,
-    io::{self, Write},
-    process::{self, Command},
+use clap::{command, value_parser, Arg, ArgAction, Command};
+use project_euler::{
+    answers::{AnswerStore, Verification, ANSWERS_FILE_PATH},
+    batch::{run_solutions, ProblemSelection, RunStatus},
+    benchmark::{
+        bench_solution, benchmark_report, record_benchmarks, regression_report, Benchmark,
+        BenchmarkOptions, BENCHMARK_HISTORY_FILE_PATH, DEFAULT_REGRESSION_THRESHOLD,
+    },
+    output::{format_solution_runs, OutputFormat, FORMAT_FLAG},
+    run_solution_with_arguments,
+    solutions::{get_solution, problem_indices},
+    ProblemIndex, OFFICIAL_PRESET, PRESET_FLAG,
 };
+use std::{ffi::OsString, iter, process::ExitCode};
 
-/// Command-line argument problem index placeholder.
-const PROBLEM_INDEX: &str = "PROBLEM_INDEX";
+/// Command-line argument problem selection placeholder.
+const PROBLEM_SELECTION: &str = "PROBLEM_SELECTION";
 
-fn main() {
-    println!();
+/// Command-line argument solution input arguments placeholder.
+const SOLUTION_ARGUMENTS: &str = "SOLUTION_ARGUMENTS";
 
-    let solution_index = get_solution_index();
+/// Command-line argument record answers flag.
+const RECORD_ANSWERS: &str = "record-answers";
 
-    let solution_path = match env::current_exe() {
-        Ok(solution_runner_exe_path) => solution_runner_exe_path
-            .parent()
-            .expect("solution runner executable to exist in a directory")
-            .join(format!("solution_{:03}.exe", solution_index)),
-        Err(err) => {
-            println!("Failed to get current exe path: {}\n", err);
-            return;
+/// Command-line argument batch preset name placeholder.
+const PRESET: &str = "PRESET";
+
+/// Command-line argument benchmark flag.
+const BENCH: &str = "bench";
+
+/// Command-line argument benchmark regression threshold placeholder.
+const REGRESSION_THRESHOLD: &str = "REGRESSION_THRESHOLD";
+
+/// Command-line argument pin benchmarks as baselines flag.
+const SET_BASELINE: &str = "set-baseline";
+
+/// Command-line argument output format placeholder.
+const FORMAT: &str = "FORMAT";
+
+/// Runner command-line arguments.
+struct RunnerArguments {
+    /// Problems to run solutions for.
+    problem_selection: ProblemSelection,
+
+    /// Solution input arguments to forward to the solution, if a single problem was selected.
+    solution_arguments: Option<Vec<OsString>>,
+
+    /// Name of the preset to run each solution with, if passed in.
+    preset_name: Option<String>,
+
+    /// Record answers of solutions with no known answer.
+    record_answers: bool,
+
+    /// Benchmark solutions instead of running them once.
+    bench: bool,
+
+    /// Median time increase, as a percentage of the baseline median, reported as a regression.
+    regression_threshold: f64,
+
+    /// Pin benchmarks as the baselines later benchmarks are compared against.
+    set_baseline: bool,
+
+    /// Output format of solution runs.
+    output_format: OutputFormat,
+}
+
+fn main() -> ExitCode {
+    let RunnerArguments {
+        problem_selection,
+        solution_arguments,
+        preset_name,
+        record_answers,
+        bench,
+        regression_threshold,
+        set_baseline,
+        output_format,
+    } = get_runner_arguments();
+
+    let print_blank_line =
+        !output_format.is_machine_readable() && (bench || solution_arguments.is_none());
+    if print_blank_line {
+        println!();
+    }
+
+    let exit_code = match (problem_selection, solution_arguments) {
+        (problem_selection, _) if bench => {
+            let problem_indices = problem_selection.problem_indices();
+            let benchmarks = bench_solutions(
+                &problem_indices,
+                preset_name.as_deref().unwrap_or(OFFICIAL_PRESET),
+            );
+            report_regressions(&benchmarks, regression_threshold, set_baseline);
+
+            if benchmarks.len() == problem_indices.len() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        (ProblemSelection::Single(problem_index), solution_arguments) => {
+            let preset_arguments = preset_name
+                .into_iter()
+                .flat_map(|preset_name| [PRESET_FLAG.to_string(), preset_name]);
+            let format_arguments = Some(output_format)
+                .filter(OutputFormat::is_machine_readable)
+                .into_iter()
+                .flat_map(|output_format| [FORMAT_FLAG.to_string(), output_format.to_string()]);
+
+            run_solution_with_arguments(
+                problem_index,
+                iter::once(format!("{} {}", env!("CARGO_BIN_NAME"), problem_index))
+                    .chain(preset_arguments)
+                    .chain(format_arguments)
+                    .map(OsString::from)
+                    .chain(solution_arguments.unwrap_or_default()),
+            )
+        }
+        (problem_selection, _) => {
+            let mut answer_store = match AnswerStore::load(ANSWERS_FILE_PATH) {
+                Ok(answer_store) => answer_store,
+                Err(err) => {
+                    eprintln!("Failed to load known answers: {}\n", err);
+                    AnswerStore::default()
+                }
+            };
+
+            let solution_runs = run_solutions(
+                &problem_selection.problem_indices(),
+                preset_name.as_deref().unwrap_or(OFFICIAL_PRESET),
+                &answer_store,
+            );
+
+            println!("{}", format_solution_runs(&solution_runs, output_format));
+
+            if !output_format.is_machine_readable() {
+                for solution_run in &solution_runs {
+                    if let RunStatus::Failed(message) = &solution_run.status {
+                        eprintln!(
+                            "\nError in problem {}'s solution: {}",
+                            solution_run.problem_index, message
+                        );
+                    }
+                }
+            }
+
+            if record_answers {
+                for solution_run in &solution_runs {
+                    if let (Some(answer), RunStatus::Completed(Verification::Unknown)) =
+                        (&solution_run.answer, &solution_run.status)
+                    {
+                        answer_store.insert(
+                            solution_run.problem_index,
+                            &solution_run.arguments,
+                            &answer.to_string(),
+                            true,
+                        );
+                    }
+                }
+
+                match answer_store.save(ANSWERS_FILE_PATH) {
+                    Ok(()) => eprintln!("\nRecorded unknown answers in {}.", ANSWERS_FILE_PATH),
+                    Err(err) => eprintln!("\nFailed to record answers: {}", err),
+                }
+            }
+
+            if solution_runs
+                .iter()
+                .all(|solution_run| solution_run.status.is_success())
+            {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
         }
     };
 
-    if !solution_path.exists() {
-        println!(
-            "No solution for problem {} has been implemented.\n",
-            solution_index,
-        );
-        return;
+    if print_blank_line {
+        println!();
+    }
+
+    exit_code
+}
+
+/// Benchmark solutions for each passed in problem and print a report.
+/// - **problem_indices:** Problem indices to benchmark solutions for.
+/// - **preset_name:** Name of the preset to benchmark solutions with.
+fn bench_solutions(problem_indices: &[ProblemIndex], preset_name: &str) -> Vec<Benchmark> {
+    let options = BenchmarkOptions::default();
+    let mut benchmarks = vec![];
+
+    for problem_index in problem_indices {
+        let solution = match get_solution(*problem_index) {
+            Some(solution) => solution,
+            None => {
+                eprintln!(
+                    "No solution for problem {} has been implemented.",
+                    problem_index
+                );
+                continue;
+            }
+        };
+
+        println!("Benchmarking problem {}'s solution...", problem_index);
+
+        match bench_solution(*problem_index, solution, preset_name, &options) {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(err) => eprintln!("Error in problem {}'s solution: {}", problem_index, err),
+        }
     }
 
-    let solution_arguments = env::args().skip(2).collect::<Vec<String>>().join(" ");
+    println!("\n{}", benchmark_report(&benchmarks));
 
-    let solution_executable = Command::new(&solution_path)
-        .arg(solution_arguments)
-        .output()
-        .expect("solution to problem to be run");
-    io::stdout()
-        .write_all(&solution_executable.stdout)
-        .expect("solution to run without interruption");
-    io::stderr()
-        .write_all(&solution_executable.stderr)
-        .expect("solution to run without interruption");
+    benchmarks
+}
 
-    println!();
+/// Record benchmarks in the benchmark history file, and print those that regressed against their
+/// baseline.
+/// - **benchmarks:** Benchmarks to record.
+/// - **regression_threshold:** Median time increase, as a percentage of the baseline median,
+///   reported as a regression.
+/// - **set_baseline:** Whether to pin the benchmarks as the new baselines.
+fn report_regressions(benchmarks: &[Benchmark], regression_threshold: f64, set_baseline: bool) {
+    match record_benchmarks(
+        BENCHMARK_HISTORY_FILE_PATH,
+        benchmarks,
+        regression_threshold,
+        set_baseline,
+    ) {
+        Ok(regressions) if regressions.is_empty() => println!(
+            "\nRecorded benchmarks in {}, no regressions found.",
+            BENCHMARK_HISTORY_FILE_PATH
+        ),
+        Ok(regressions) => println!(
+            "\nRecorded benchmarks in {}, regressions of more than {}% found:\n\n{}",
+            BENCHMARK_HISTORY_FILE_PATH,
+            regression_threshold,
+            regression_report(&regressions)
+        ),
+        Err(err) => println!("\nFailed to record benchmarks: {}", err),
+    }
 }
 
-/// Get solution index from command-line arguments.
-fn get_solution_index() -> ProblemIndex {
-    let mut command = command!()
+/// Get subcommand that forwards its arguments to the solution registered for the passed in
+/// problem.
+/// - **problem_index:** Problem index of solution.
+fn solution_subcommand(problem_index: ProblemIndex) -> Command {
+    let solution = get_solution(problem_index).expect("solution to be registered");
+
+    Command::new(problem_index.to_string())
+        .alias(format!("{:03}", problem_index))
+        .about(solution.title())
         .disable_help_flag(true)
+        .arg(
+            Arg::new(SOLUTION_ARGUMENTS)
+                .num_args(0..)
+                .trailing_var_arg(true)
+                .allow_hyphen_values(true)
+                .value_parser(value_parser!(OsString))
+                .help("Solution input arguments, --preset NAME, --format FORMAT or --help."),
+        )
+}
+
+/// Get runner arguments from command-line arguments.
+fn get_runner_arguments() -> RunnerArguments {
+    let mut arg_matches = command!()
         .disable_version_flag(true)
-        .ignore_errors(true)
-        .allow_external_subcommands(true)
-        .about("Solution runner for Project Euler problems.")
+        .disable_help_subcommand(true)
+        .arg_required_else_help(true)
+        .subcommand_negates_reqs(true)
+        .override_usage(format!(
+            "{0} [OPTIONS] <PROBLEM_SELECTION>\n       \
+            {0} [OPTIONS] <PROBLEM> [SOLUTION_ARGUMENTS]...",
+            env!("CARGO_BIN_NAME")
+        ))
+        .subcommand_help_heading("Problems")
+        .about(
+            "Solution runner for Project Euler problems. Run a single problem's solution with \
+            `PROBLEM [SOLUTION_ARGUMENTS]...`, where `PROBLEM --help` shows the solution's \
+            arguments, or run several solutions with `PROBLEM_SELECTION`.",
+        )
+        .subcommands(problem_indices().map(solution_subcommand))
         .arg(
-            Arg::new(PROBLEM_INDEX)
+            Arg::new(PROBLEM_SELECTION)
                 .required(true)
-                .value_parser(value_parser!(ProblemIndex))
-                .help("Problem number to run solution for."),
-        );
-
-    let arg_matches = command.get_matches_mut();
-    match arg_matches.get_one::<ProblemIndex>(PROBLEM_INDEX) {
-        Some(problem_index) => *problem_index,
-        None => {
-            command
-                .print_long_help()
-                .expect("help message to be printed");
+                .value_parser(|selection: &str| selection.parse::<ProblemSelection>())
+                .help(
+                    "Problems to run solutions for: all, a range (1-17) or a list (3,7,11 or \
+                    1-4,6).",
+                ),
+        )
+        .arg(
+            Arg::new(PRESET)
+                .long(PRESET_FLAG.trim_start_matches('-'))
+                .value_name(PRESET)
+                .help("Preset to run each solution with, e.g. official or example."),
+        )
+        .arg(
+            Arg::new(RECORD_ANSWERS)
+                .long(RECORD_ANSWERS)
+                .action(ArgAction::SetTrue)
+                .help(format!(
+                    "Record hashed answers of solutions with no known answer in {}.",
+                    ANSWERS_FILE_PATH
+                )),
+        )
+        .arg(Arg::new(BENCH).long(BENCH).action(ArgAction::SetTrue).help(
+            "Benchmark solutions with warm-up and repeated runs, and print their timing \
+            statistics. Build with --release for meaningful timings.",
+        ))
+        .arg(
+            Arg::new(REGRESSION_THRESHOLD)
+                .long("regression-threshold")
+                .value_name(REGRESSION_THRESHOLD)
+                .value_parser(value_parser!(f64))
+                .help(format!(
+                    "Median time increase, as a percentage of the baseline benchmark recorded in \
+                    {}, above which a benchmark is reported as a regression. Defaults to {}.",
+                    BENCHMARK_HISTORY_FILE_PATH, DEFAULT_REGRESSION_THRESHOLD
+                )),
+        )
+        .arg(
+            Arg::new(SET_BASELINE)
+                .long(SET_BASELINE)
+                .action(ArgAction::SetTrue)
+                .help(
+                    "Pin the benchmarks as the baselines later benchmarks are compared against, \
+                    instead of the best recorded median.",
+                ),
+        )
+        .arg(
+            Arg::new(FORMAT)
+                .long(FORMAT_FLAG.trim_start_matches('-'))
+                .value_name(FORMAT)
+                .value_parser(|format: &str| format.parse::<OutputFormat>())
+                .help("Output format of solution runs: text, json, csv or tsv."),
+        )
+        .get_matches();
 
-            println!();
+    let (problem_selection, solution_arguments) = match arg_matches.remove_subcommand() {
+        Some((problem_index, mut subcommand_matches)) => (
+            ProblemSelection::Single(
+                problem_index
+                    .parse()
+                    .expect("subcommand name to be a problem index"),
+            ),
+            Some(
+                subcommand_matches
+                    .remove_many::<OsString>(SOLUTION_ARGUMENTS)
+                    .map(Iterator::collect)
+                    .unwrap_or_default(),
+            ),
+        ),
+        None => (
+            arg_matches
+                .remove_one::<ProblemSelection>(PROBLEM_SELECTION)
+                .expect("problem selection to be required"),
+            None,
+        ),
+    };
 
-            process::exit(0);
-        }
+    RunnerArguments {
+        problem_selection,
+        solution_arguments,
+        preset_name: arg_matches.remove_one::<String>(PRESET),
+        record_answers: arg_matches.get_flag(RECORD_ANSWERS),
+        bench: arg_matches.get_flag(BENCH),
+        regression_threshold: arg_matches
+            .remove_one::<f64>(REGRESSION_THRESHOLD)
+            .unwrap_or(DEFAULT_REGRESSION_THRESHOLD),
+        set_baseline: arg_matches.get_flag(SET_BASELINE),
+        output_format: arg_matches
+            .remove_one::<OutputFormat>(FORMAT)
+            .unwrap_or_default(),
     }
 }
@@ -0,0 +1,104 @@
+use std::{
+    fmt::{self, Display},
+    io,
+};
+
+/// Project Euler solution and input helper error.
+#[derive(Debug)]
+pub enum Error {
+    /// File could not be read or written.
+    Io {
+        /// Path of the file being accessed.
+        file_path: String,
+
+        /// Underlying I/O error.
+        source: io::Error,
+    },
+
+    /// File contents could not be parsed.
+    Parse {
+        /// Path of the file being parsed.
+        file_path: String,
+
+        /// Line number, starting at 1.
+        line: usize,
+
+        /// Column number, starting at 1.
+        column: usize,
+
+        /// Description of what could not be parsed.
+        message: String,
+    },
+
+    /// Solution input arguments could not be parsed.
+    Arguments {
+        /// Command-line argument parser error message.
+        message: String,
+    },
+
+    /// Solution has no preset with the requested name.
+    UnknownPreset {
+        /// Requested preset name.
+        preset: String,
+
+        /// Names of the presets the solution has.
+        presets: Vec<String>,
+    },
+
+    /// Solution input argument is not valid for the solution.
+    InvalidArgument {
+        /// Input argument placeholder.
+        argument: String,
+
+        /// Description of why the input argument is not valid.
+        message: String,
+    },
+}
+
+impl Error {
+    /// Create error for an input argument that is not valid for the solution.
+    /// - **argument:** Input argument placeholder.
+    /// - **message:** Description of why the input argument is not valid.
+    pub fn invalid_argument(argument: &str, message: impl Into<String>) -> Self {
+        Self::InvalidArgument {
+            argument: argument.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { file_path, source } => write!(formatter, "{}: {}", file_path, source),
+            Self::Parse {
+                file_path,
+                line,
+                column,
+                message,
+            } => write!(formatter, "{}:{}:{}: {}", file_path, line, column, message),
+            Self::Arguments { message } => write!(formatter, "{}", message.trim_end()),
+            Self::UnknownPreset { preset, presets } => write!(
+                formatter,
+                "unknown preset '{}', expected one of: {}",
+                preset,
+                presets.join(", ")
+            ),
+            Self::InvalidArgument { argument, message } => {
+                write!(formatter, "invalid {}: {}", argument, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Project Euler solution and input helper result.
+pub type Result<T> = std::result::Result<T, Error>;
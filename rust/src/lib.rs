@@ -1,19 +1,34 @@
-#![feature(is_some_and)]
-#![feature(iter_advance_by)]
-#![feature(test)]
+mod answer;
+mod error;
+mod preset;
+mod table;
 
+pub mod answers;
+pub mod batch;
+pub mod benchmark;
+pub mod output;
 pub mod shared;
+pub mod solutions;
 
-use clap::{command, Arg, ArgMatches};
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::env;
+pub use answer::Answer;
+pub use error::{Error, Result};
+pub use preset::{Preset, EXAMPLE_PRESET, OFFICIAL_PRESET};
+
+use answers::{AnswerStore, Verification, ANSWERS_FILE_PATH};
+use batch::{run_parsed_solution, RunStatus};
+use clap::{command, Arg, ArgMatches, Command};
+use output::{format_solution_runs, OutputFormat, FORMAT_FLAG};
+use solutions::get_solution;
+use std::{env, ffi::OsString, iter, process::ExitCode, slice};
 
 /// Command-line argument problem index type.
 pub type ProblemIndex = u16;
 
+/// Command-line argument preset flag.
+pub const PRESET_FLAG: &str = "--preset";
+
 /// Represents a Project Euler problem solution.
-pub trait Solution {
+pub trait Solution: Sync {
     /// Get solution title.
     fn title(&self) -> String;
 
@@ -23,44 +38,348 @@ pub trait Solution {
     /// Get solution input arguments.
     fn arguments(&self) -> Vec<Arg>;
 
+    /// Get named solution input argument presets, including the `example` and `official` presets.
+    fn presets(&self) -> Vec<Preset>;
+
     /// Run solution.
-    fn run(&self, arguments: &ArgMatches) -> u64;
-}
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer>;
 
-/// Run the passed in solution.
-/// - **solution:** Solution to be run.
-pub fn run_solution(solution: &dyn Solution) {
-    lazy_static! {
-        static ref SOLUTION_INDEX_REGEX: Regex =
-            Regex::new(r"solution_(\d{3}).exe$").expect("RegEx to be valid");
+    /// Get solution input argument preset.
+    /// - **preset_name:** Name of the preset to get.
+    fn preset(&self, preset_name: &str) -> Result<Preset> {
+        let presets = self.presets();
+
+        match presets.iter().position(|preset| preset.name == preset_name) {
+            Some(preset_index) => Ok(presets[preset_index].clone()),
+            None => Err(Error::UnknownPreset {
+                preset: preset_name.to_string(),
+                presets: presets
+                    .iter()
+                    .map(|preset| preset.name.to_string())
+                    .collect(),
+            }),
+        }
     }
+}
+
+/// Exit code of a process given command-line arguments that are not valid.
+pub const USAGE_EXIT_CODE: u8 = 2;
 
-    let solution_path = match env::current_exe() {
-        Ok(exe_path) => exe_path.display().to_string(),
+/// Run the solution registered for the passed in problem, using the process' command-line
+/// arguments as solution input arguments.
+/// - **problem_index:** Problem index to run solution for.
+pub fn run_solution(problem_index: ProblemIndex) -> ExitCode {
+    run_solution_with_arguments(problem_index, env::args_os())
+}
+
+/// Run the solution registered for the passed in problem.
+/// - **problem_index:** Problem index to run solution for.
+/// - **arguments:** Command-line arguments to parse solution input arguments from. The first
+///   argument is treated as the binary name.
+/// ## Notes
+/// - When no solution input arguments are passed in, the official preset is run. Passing
+///   `--preset NAME` runs the named preset instead.
+/// - Passing `--format FORMAT` writes the solution run as a `json`, `csv` or `tsv` record instead
+///   of text.
+/// - **Exit code:** Success when the solution ran to completion without a wrong answer, 2 when the
+///   command-line arguments are not valid, and failure otherwise.
+pub fn run_solution_with_arguments<I, T>(problem_index: ProblemIndex, arguments: I) -> ExitCode
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let solution = match get_solution(problem_index) {
+        Some(solution) => solution,
+        None => {
+            println!(
+                "No solution for problem {} has been implemented.\n",
+                problem_index
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut arguments = arguments.into_iter().map(Into::into);
+    let binary_name = arguments
+        .next()
+        .unwrap_or_else(|| format!("solution_{:03}", problem_index).into());
+    let mut solution_arguments: Vec<String> = arguments
+        .map(|argument| argument.to_string_lossy().to_string())
+        .collect();
+    let (output_format, solution_arguments) = match take_output_format(&mut solution_arguments)
+        .and_then(|output_format| {
+            Ok((
+                output_format,
+                resolve_preset_arguments(solution, solution_arguments)?,
+            ))
+        }) {
+        Ok(resolved_arguments) => resolved_arguments,
         Err(err) => {
-            println!("Failed to get current exe path: {}\n", err);
-            return;
+            eprintln!("Error: {}", err);
+            return ExitCode::from(USAGE_EXIT_CODE);
         }
     };
+    let arguments = solution_command(solution).get_matches_from(
+        iter::once(binary_name).chain(solution_arguments.iter().map(OsString::from)),
+    );
 
-    let solution_index = match SOLUTION_INDEX_REGEX.captures(&solution_path) {
-        Some(captures) => captures[1]
-            .parse::<ProblemIndex>()
-            .expect("RegEx to only capture digits"),
-        None => {
-            println!("Failed to get solution exe path in: {}\n", solution_path);
-            return;
+    if !output_format.is_machine_readable() {
+        println!("Running problem {}'s solution...\n", problem_index);
+    }
+
+    let answer_store = match AnswerStore::load(ANSWERS_FILE_PATH) {
+        Ok(answer_store) => answer_store,
+        Err(err) => {
+            eprintln!("Failed to load known answers: {}", err);
+            AnswerStore::default()
         }
     };
 
-    let arguments = command!()
+    let solution_run = run_parsed_solution(
+        problem_index,
+        solution,
+        solution_arguments,
+        &arguments,
+        &answer_store,
+    );
+
+    if output_format.is_machine_readable() {
+        println!(
+            "{}",
+            format_solution_runs(slice::from_ref(&solution_run), output_format)
+        );
+    } else if let (Some(answer), RunStatus::Completed(verification)) =
+        (&solution_run.answer, &solution_run.status)
+    {
+        println!("Answer: {}", answer);
+        println!("Verification: {}", verification);
+    } else if let RunStatus::Failed(message) = &solution_run.status {
+        eprintln!("Error: {}", message);
+    }
+
+    if solution_run.status.is_success() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Remove a flag and its value from command-line arguments, returning the value if the flag is
+/// present. Both `--flag VALUE` and `--flag=VALUE` are supported.
+/// - **arguments:** Command-line arguments to remove flag from.
+/// - **flag:** Flag to remove, e.g. `--preset`.
+fn take_flag_value(arguments: &mut Vec<String>, flag: &str) -> Result<Option<String>> {
+    for (argument_index, argument) in arguments.iter().enumerate() {
+        if argument == flag {
+            if argument_index + 1 == arguments.len() {
+                return Err(Error::Arguments {
+                    message: format!("a value is required for '{}'", flag),
+                });
+            }
+
+            return Ok(arguments.drain(argument_index..=argument_index + 1).nth(1));
+        }
+
+        if let Some(value) = argument
+            .strip_prefix(flag)
+            .and_then(|value| value.strip_prefix('='))
+        {
+            let value = value.to_string();
+            arguments.remove(argument_index);
+
+            return Ok(Some(value));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Remove output format flag from command-line arguments, returning the output format.
+/// - **arguments:** Command-line arguments to remove output format flag from.
+fn take_output_format(arguments: &mut Vec<String>) -> Result<OutputFormat> {
+    match take_flag_value(arguments, FORMAT_FLAG)? {
+        Some(output_format) => output_format
+            .parse()
+            .map_err(|message| Error::Arguments { message }),
+        None => Ok(OutputFormat::default()),
+    }
+}
+
+/// Resolve solution input arguments, replacing no arguments with the official preset arguments
+/// and `--preset NAME` with the named preset arguments.
+/// - **solution:** Solution to resolve input arguments for.
+/// - **arguments:** Solution input arguments, without a binary name.
+fn resolve_preset_arguments(
+    solution: &dyn Solution,
+    mut arguments: Vec<String>,
+) -> Result<Vec<String>> {
+    match take_flag_value(&mut arguments, PRESET_FLAG)? {
+        Some(preset_name) if arguments.is_empty() => preset_arguments(solution, &preset_name),
+        Some(_) => Err(Error::Arguments {
+            message: format!(
+                "'{}' cannot be used with solution input arguments",
+                PRESET_FLAG
+            ),
+        }),
+        None if arguments.is_empty() => preset_arguments(solution, OFFICIAL_PRESET),
+        None => Ok(arguments),
+    }
+}
+
+/// Get solution input arguments of a preset.
+/// - **solution:** Solution to get preset input arguments for.
+/// - **preset_name:** Name of the preset to get input arguments of.
+pub fn preset_arguments(solution: &dyn Solution, preset_name: &str) -> Result<Vec<String>> {
+    Ok(solution
+        .preset(preset_name)?
+        .arguments
+        .into_iter()
+        .map(str::to_string)
+        .collect())
+}
+
+/// Parse solution input arguments.
+/// - **solution:** Solution to parse input arguments for.
+/// - **arguments:** Solution input arguments, without a binary name.
+pub fn parse_arguments(solution: &dyn Solution, arguments: &[String]) -> Result<ArgMatches> {
+    solution_command(solution)
+        .no_binary_name(true)
+        .try_get_matches_from(arguments)
+        .map_err(|err| Error::Arguments {
+            message: err.to_string(),
+        })
+}
+
+/// Run solution using the input arguments of a preset.
+/// - **solution:** Solution to run.
+/// - **preset_name:** Name of the preset to run solution with.
+pub fn run_preset(solution: &dyn Solution, preset_name: &str) -> Result<Answer> {
+    solution.run(&parse_arguments(
+        solution,
+        &preset_arguments(solution, preset_name)?,
+    )?)
+}
+
+/// Compare answer against the expected answer of the preset with the same input arguments, or
+/// otherwise against the known answer in the answer store.
+/// - **solution:** Solution answer is from.
+/// - **problem_index:** Problem index answer is for.
+/// - **arguments:** Solution input arguments used to get answer.
+/// - **answer:** Answer to verify.
+/// - **answer_store:** Known answers.
+pub fn verify_answer(
+    solution: &dyn Solution,
+    problem_index: ProblemIndex,
+    arguments: &[String],
+    answer: &Answer,
+    answer_store: &AnswerStore,
+) -> Verification {
+    let preset_answer = solution
+        .presets()
+        .into_iter()
+        .find(|preset| preset.has_arguments(arguments))
+        .and_then(|preset| preset.answer);
+
+    match preset_answer {
+        Some(preset_answer) if preset_answer == *answer => Verification::Correct,
+        Some(_) => Verification::Wrong,
+        None => answer_store.verify(problem_index, arguments, &answer.to_string()),
+    }
+}
+
+/// Get command-line argument parser for the passed in solution.
+/// - **solution:** Solution to get command-line argument parser for.
+pub fn solution_command(solution: &dyn Solution) -> Command {
+    let presets_help = solution
+        .presets()
+        .iter()
+        .map(|preset| format!("  {}: {}", preset.name, preset.arguments.join(" ")))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    command!()
         .disable_version_flag(true)
         .before_help(solution.title())
         .about(solution.description())
         .args(solution.arguments())
-        .get_matches();
+        .arg(
+            Arg::new(FORMAT_FLAG.trim_start_matches('-'))
+                .long(FORMAT_FLAG.trim_start_matches('-'))
+                .value_name("FORMAT")
+                .value_parser(OutputFormat::NAMES)
+                .help("Output format of the solution run."),
+        )
+        .after_help(format!(
+            "Presets (run with {} NAME, or without arguments to run the {} preset):\n{}",
+            PRESET_FLAG, OFFICIAL_PRESET, presets_help
+        ))
+}
 
-    println!("Running problem {}'s solution...\n", solution_index);
+#[cfg(test)]
+mod tests {
+    use super::{resolve_preset_arguments, take_flag_value, take_output_format};
+    use crate::{output::OutputFormat, solutions::get_solution};
+
+    fn arguments(arguments: &[&str]) -> Vec<String> {
+        arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect()
+    }
 
-    println!("Answer: {}", solution.run(&arguments));
+    #[test]
+    fn take_flag_value_removes_flag_and_value() {
+        let mut separate_value = arguments(&["10", "--format", "json"]);
+        let mut joined_value = arguments(&["--format=csv", "10"]);
+        let mut missing_flag = arguments(&["10"]);
+
+        assert_eq!(
+            take_flag_value(&mut separate_value, "--format").expect("flag to have a value"),
+            Some("json".to_string())
+        );
+        assert_eq!(separate_value, arguments(&["10"]));
+        assert_eq!(
+            take_flag_value(&mut joined_value, "--format").expect("flag to have a value"),
+            Some("csv".to_string())
+        );
+        assert_eq!(joined_value, arguments(&["10"]));
+        assert_eq!(
+            take_flag_value(&mut missing_flag, "--format").expect("flag to be optional"),
+            None
+        );
+        assert!(take_flag_value(&mut arguments(&["10", "--format"]), "--format").is_err());
+    }
+
+    #[test]
+    fn take_output_format_rejects_unknown_formats() {
+        assert_eq!(
+            take_output_format(&mut arguments(&["--format", "tsv"]))
+                .expect("output format to be valid"),
+            OutputFormat::Tsv
+        );
+        assert!(take_output_format(&mut arguments(&["--format", "xml"])).is_err());
+    }
+
+    #[test]
+    fn resolve_preset_arguments_replaces_presets_with_their_arguments() {
+        let solution = get_solution(1).expect("solution to be registered");
+
+        assert_eq!(
+            resolve_preset_arguments(solution, vec![]).expect("official preset to exist"),
+            arguments(&["1000"])
+        );
+        assert_eq!(
+            resolve_preset_arguments(solution, arguments(&["--preset", "example"]))
+                .expect("example preset to exist"),
+            arguments(&["10"])
+        );
+        assert_eq!(
+            resolve_preset_arguments(solution, arguments(&["20"]))
+                .expect("arguments to be passed through"),
+            arguments(&["20"])
+        );
+        assert!(
+            resolve_preset_arguments(solution, arguments(&["--preset=example", "20"])).is_err()
+        );
+    }
 }
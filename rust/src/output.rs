@@ -0,0 +1,306 @@
+use crate::batch::{summary_table, RunStatus, SolutionRun};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Command-line argument output format flag.
+pub const FORMAT_FLAG: &str = "--format";
+
+/// Solution run record fields, in output order.
+const FIELDS: [&str; 8] = [
+    "problem",
+    "title",
+    "preset",
+    "arguments",
+    "answer",
+    "elapsed_seconds",
+    "status",
+    "error",
+];
+
+/// Format solution runs are written in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Human readable text.
+    #[default]
+    Text,
+
+    /// JSON array with an object per solution run.
+    Json,
+
+    /// Comma separated values with a header row.
+    Csv,
+
+    /// Tab separated values with a header row.
+    Tsv,
+}
+
+impl OutputFormat {
+    /// Names of the output formats.
+    pub const NAMES: [&'static str; 4] = ["text", "json", "csv", "tsv"];
+
+    /// Check if output format is meant to be read by other programs rather than people.
+    pub fn is_machine_readable(&self) -> bool {
+        *self != Self::Text
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.pad(match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+        })
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.trim().to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(format!(
+                "'{}' is not an output format, expected one of: {}",
+                format,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+/// Format solution runs, one record per run. Text output is the batch summary table.
+/// - **solution_runs:** Solution runs to format.
+/// - **format:** Format to write solution runs in.
+pub fn format_solution_runs(solution_runs: &[SolutionRun], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Text => summary_table(solution_runs),
+        OutputFormat::Json => {
+            let objects: Vec<String> = solution_runs
+                .iter()
+                .map(|solution_run| {
+                    let values = [
+                        solution_run.problem_index.to_string(),
+                        json_string(&solution_run.title),
+                        json_optional_string(solution_run.preset.as_deref()),
+                        format!(
+                            "[{}]",
+                            solution_run
+                                .arguments
+                                .iter()
+                                .map(|argument| json_string(argument))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ),
+                        json_optional_string(
+                            solution_run
+                                .answer
+                                .as_ref()
+                                .map(|answer| answer.to_string())
+                                .as_deref(),
+                        ),
+                        elapsed_seconds(solution_run),
+                        json_string(&solution_run.status.to_string()),
+                        json_optional_string(error_message(solution_run)),
+                    ];
+
+                    let members: Vec<String> = FIELDS
+                        .iter()
+                        .zip(values)
+                        .map(|(field, value)| format!("\"{}\": {}", field, value))
+                        .collect();
+
+                    format!("  {{{}}}", members.join(", "))
+                })
+                .collect();
+
+            if objects.is_empty() {
+                "[]".to_string()
+            } else {
+                format!("[\n{}\n]", objects.join(",\n"))
+            }
+        }
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            let (separator, escape): (&str, fn(&str) -> String) = match format {
+                OutputFormat::Csv => (",", csv_field),
+                _ => ("\t", tsv_field),
+            };
+
+            let mut lines = vec![FIELDS.join(separator)];
+            lines.extend(solution_runs.iter().map(|solution_run| {
+                [
+                    solution_run.problem_index.to_string(),
+                    solution_run.title.clone(),
+                    solution_run.preset.clone().unwrap_or_default(),
+                    solution_run.arguments.join(" "),
+                    solution_run
+                        .answer
+                        .as_ref()
+                        .map(|answer| answer.to_string())
+                        .unwrap_or_default(),
+                    elapsed_seconds(solution_run),
+                    solution_run.status.to_string(),
+                    error_message(solution_run).unwrap_or_default().to_string(),
+                ]
+                .map(|value| escape(&value))
+                .join(separator)
+            }));
+
+            lines.join("\n")
+        }
+    }
+}
+
+/// Get wall time taken to run the solution in seconds.
+/// - **solution_run:** Solution run to get elapsed time of.
+fn elapsed_seconds(solution_run: &SolutionRun) -> String {
+    solution_run.elapsed.as_secs_f64().to_string()
+}
+
+/// Get error message of a failed solution run.
+/// - **solution_run:** Solution run to get error message of.
+fn error_message(solution_run: &SolutionRun) -> Option<&str> {
+    match &solution_run.status {
+        RunStatus::Failed(message) => Some(message),
+        _ => None,
+    }
+}
+
+/// Get text as a JSON string, escaping quotes, backslashes and control characters.
+/// - **text:** Text to get as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut json_string = String::with_capacity(text.len() + 2);
+    json_string.push('"');
+
+    for character in text.chars() {
+        match character {
+            '"' => json_string.push_str("\\\""),
+            '\\' => json_string.push_str("\\\\"),
+            '\n' => json_string.push_str("\\n"),
+            '\r' => json_string.push_str("\\r"),
+            '\t' => json_string.push_str("\\t"),
+            character if character.is_control() => {
+                json_string.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => json_string.push(character),
+        }
+    }
+
+    json_string.push('"');
+    json_string
+}
+
+/// Get optional text as a JSON string, or `null` if there is no text.
+/// - **text:** Optional text to get as a JSON string.
+fn json_optional_string(text: Option<&str>) -> String {
+    text.map_or_else(|| "null".to_string(), json_string)
+}
+
+/// Get text as a CSV field, quoting it if it contains a comma, quote or line break.
+/// - **text:** Text to get as a CSV field.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Get text as a TSV field, replacing tabs and line breaks with spaces.
+/// - **text:** Text to get as a TSV field.
+fn tsv_field(text: &str) -> String {
+    text.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_solution_runs, OutputFormat};
+    use crate::{
+        answers::Verification,
+        batch::{RunStatus, SolutionRun},
+        Answer,
+    };
+    use std::time::Duration;
+
+    fn solution_runs() -> Vec<SolutionRun> {
+        vec![
+            SolutionRun {
+                problem_index: 13,
+                preset: Some("example".to_string()),
+                arguments: vec![".test_input/013.dat".to_string(), "2".to_string()],
+                title: "Large sum".to_string(),
+                answer: Some(Answer::from("27")),
+                elapsed: Duration::from_millis(1_500),
+                status: RunStatus::Completed(Verification::Correct),
+            },
+            SolutionRun {
+                problem_index: 8,
+                preset: None,
+                arguments: vec!["a \"quoted\", tabbed\targument".to_string()],
+                title: "Largest product in a series".to_string(),
+                answer: None,
+                elapsed: Duration::ZERO,
+                status: RunStatus::Failed("file not found\nat line 1".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn output_format_parses_format_names() {
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("CSV".parse(), Ok(OutputFormat::Csv));
+        for name in OutputFormat::NAMES {
+            assert_eq!(
+                name.parse::<OutputFormat>()
+                    .expect("output format name to be parsable")
+                    .to_string(),
+                name
+            );
+        }
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn format_solution_runs_writes_json_records() {
+        assert_eq!(
+            format_solution_runs(&solution_runs(), OutputFormat::Json),
+            "[\n  \
+            {\"problem\": 13, \"title\": \"Large sum\", \"preset\": \"example\", \
+            \"arguments\": [\".test_input/013.dat\", \"2\"], \"answer\": \"27\", \
+            \"elapsed_seconds\": 1.5, \"status\": \"correct\", \"error\": null},\n  \
+            {\"problem\": 8, \"title\": \"Largest product in a series\", \"preset\": null, \
+            \"arguments\": [\"a \\\"quoted\\\", tabbed\\targument\"], \"answer\": null, \
+            \"elapsed_seconds\": 0, \"status\": \"failed\", \
+            \"error\": \"file not found\\nat line 1\"}\n]"
+        );
+        assert_eq!(format_solution_runs(&[], OutputFormat::Json), "[]");
+    }
+
+    #[test]
+    fn format_solution_runs_writes_csv_records() {
+        assert_eq!(
+            format_solution_runs(&solution_runs(), OutputFormat::Csv),
+            "problem,title,preset,arguments,answer,elapsed_seconds,status,error\n\
+            13,Large sum,example,.test_input/013.dat 2,27,1.5,correct,\n\
+            8,Largest product in a series,,\"a \"\"quoted\"\", tabbed\targument\",,0,failed,\
+            \"file not found\nat line 1\""
+        );
+    }
+
+    #[test]
+    fn format_solution_runs_writes_tsv_records() {
+        assert_eq!(
+            format_solution_runs(&solution_runs(), OutputFormat::Tsv),
+            "problem\ttitle\tpreset\targuments\tanswer\telapsed_seconds\tstatus\terror\n\
+            13\tLarge sum\texample\t.test_input/013.dat 2\t27\t1.5\tcorrect\t\n\
+            8\tLargest product in a series\t\ta \"quoted\", tabbed argument\t\t0\tfailed\t\
+            file not found at line 1"
+        );
+    }
+}
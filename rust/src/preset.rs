@@ -0,0 +1,55 @@
+use crate::Answer;
+
+/// Name of the preset holding the arguments of a problem's worked example.
+pub const EXAMPLE_PRESET: &str = "example";
+
+/// Name of the preset holding the arguments of the official Project Euler problem.
+pub const OFFICIAL_PRESET: &str = "official";
+
+/// Named set of solution input arguments.
+#[derive(Clone, Debug)]
+pub struct Preset {
+    /// Preset name.
+    pub name: &'static str,
+
+    /// Solution input arguments.
+    pub arguments: Vec<&'static str>,
+
+    /// Expected answer, if known and not a spoiler.
+    pub answer: Option<Answer>,
+}
+
+impl Preset {
+    /// Create preset for a problem's worked example.
+    /// - **arguments:** Solution input arguments of the worked example.
+    /// - **answer:** Answer given in the worked example.
+    pub fn example(arguments: Vec<&'static str>, answer: impl Into<Answer>) -> Self {
+        Self {
+            name: EXAMPLE_PRESET,
+            arguments,
+            answer: Some(answer.into()),
+        }
+    }
+
+    /// Create preset for the official Project Euler problem.
+    /// - **arguments:** Solution input arguments of the official problem.
+    /// ## Notes
+    /// - The official answer is left out to avoid spoiling it, see `answers::AnswerStore` for
+    ///   verifying official answers.
+    pub fn official(arguments: Vec<&'static str>) -> Self {
+        Self {
+            name: OFFICIAL_PRESET,
+            arguments,
+            answer: None,
+        }
+    }
+
+    /// Check if preset has the passed in solution input arguments.
+    /// - **arguments:** Solution input arguments to compare against.
+    pub fn has_arguments(&self, arguments: &[String]) -> bool {
+        self.arguments
+            .iter()
+            .copied()
+            .eq(arguments.iter().map(String::as_str))
+    }
+}
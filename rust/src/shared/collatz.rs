@@ -0,0 +1,307 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicU32, Ordering},
+    thread,
+};
+
+/// Number of consecutive start numbers each thread scans at a time.
+const SCAN_BLOCK_LENGTH: u64 = 1 << 12;
+
+/// Largest number of start numbers a cache stores lengths for, keeping it to 64 MiB.
+pub const MAX_CACHE_LIMIT: usize = 1 << 24;
+
+/// Longest Collatz sequence chain found by a scan.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LongestChain {
+    /// Start number of the chain.
+    pub start_number: u64,
+
+    /// Number of terms in the chain, including the start number and 1.
+    pub length: u64,
+
+    /// Terms of the chain, from the start number down to 1, widened as terms can pass `u64::MAX`.
+    pub chain: Vec<u128>,
+}
+
+/// Memoized Collatz sequence chain lengths.
+/// ## Notes
+/// - **Length Table:** Lengths of chains starting below the cache limit are stored once found, so
+///   later chains stop as soon as they reach a stored start number.
+/// - **Odd Step Shortcut:** 3n + 1 is always even for odd n, so both steps are taken at once as
+///   (3n + 1) / 2.
+/// - Lengths are stored atomically, so the cache can be filled from several threads at once.
+pub struct CollatzCache {
+    /// Chain length of each start number below the limit, or 0 if not found yet.
+    lengths: Vec<AtomicU32>,
+}
+
+impl CollatzCache {
+    /// Create a cache for chains starting below a limit.
+    /// - **limit:** Exclusive upper bound of the start numbers to store lengths for.
+    /// ## Notes
+    /// - The limit is capped at `MAX_CACHE_LIMIT`, and lengths of chains starting above it are
+    ///   found by stepping down to a stored start number.
+    pub fn new(limit: usize) -> Self {
+        let lengths: Vec<AtomicU32> = (0..limit.clamp(2, MAX_CACHE_LIMIT))
+            .map(|_| AtomicU32::new(0))
+            .collect();
+        lengths[1].store(1, Ordering::Relaxed);
+
+        Self { lengths }
+    }
+
+    /// Get exclusive upper bound of the start numbers lengths are stored for.
+    pub fn limit(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Get stored chain length of a start number, if it is below the limit and found.
+    /// - **start_number:** Start number to get the stored length of.
+    fn stored_length(&self, start_number: u64) -> Option<u64> {
+        let length = self
+            .lengths
+            .get(usize::try_from(start_number).ok()?)?
+            .load(Ordering::Relaxed);
+
+        (length > 0).then_some(u64::from(length))
+    }
+
+    /// Get number of terms in the Collatz sequence chain of a start number, including the start
+    /// number and 1.
+    /// - **start_number:** Positive start number of the chain.
+    pub fn length(&self, start_number: u64) -> u64 {
+        assert!(start_number > 0, "Collatz sequences start from 1 or above");
+
+        let mut uncached_terms: Vec<(usize, u64)> = vec![];
+        let mut steps = 0;
+        let mut term = start_number;
+
+        let tail_length = loop {
+            if let Some(length) = self.stored_length(term) {
+                break length;
+            } else if (term as usize) < self.lengths.len() {
+                uncached_terms.push((term as usize, steps));
+            }
+
+            if term.is_multiple_of(2) {
+                term /= 2;
+                steps += 1;
+            } else {
+                match term.checked_mul(3).and_then(|term| term.checked_add(1)) {
+                    Some(next_term) => {
+                        term = next_term / 2;
+                        steps += 2;
+                    }
+                    None => {
+                        let (wide_steps, next_term) = Self::steps_above_u64(term);
+                        term = next_term;
+                        steps += wide_steps;
+                    }
+                }
+            }
+        };
+
+        for (index, term_steps) in uncached_terms {
+            let length = u32::try_from(steps - term_steps + tail_length)
+                .expect("Collatz chain length to fit in a u32");
+            self.lengths[index].store(length, Ordering::Relaxed);
+        }
+
+        steps + tail_length
+    }
+
+    /// Step through a Collatz sequence in 128-bit integers until it falls back into `u64` range.
+    /// - **term:** Odd term whose next term does not fit in a `u64`.
+    /// ## Notes
+    /// - Returns the number of steps taken and the first term back in range.
+    fn steps_above_u64(term: u64) -> (u64, u64) {
+        let mut steps = 0;
+        let mut term = u128::from(term);
+
+        loop {
+            if term.is_multiple_of(2) {
+                term /= 2;
+                steps += 1;
+            } else {
+                term = term
+                    .checked_mul(3)
+                    .and_then(|term| term.checked_add(1))
+                    .expect("Collatz term to fit in a u128")
+                    / 2;
+                steps += 2;
+            }
+
+            if let Ok(term) = u64::try_from(term) {
+                return (steps, term);
+            }
+        }
+    }
+
+    /// Find the longest Collatz sequence chain starting below a threshold, scanning on every
+    /// available thread.
+    /// - **start_number_threshold:** Exclusive upper bound of the start numbers.
+    /// ## Notes
+    /// - The smallest start number wins ties. Returns `None` if there is no start number below
+    ///   the threshold.
+    pub fn longest_chain_below(&self, start_number_threshold: u64) -> Option<LongestChain> {
+        let thread_count = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1) as u64;
+
+        let (start_number, length) = thread::scope(|scope| {
+            let scans: Vec<_> = (0..thread_count)
+                .map(|thread_index| {
+                    scope.spawn(move || {
+                        let mut longest: Option<(u64, u64)> = None;
+                        let mut block_start = 1 + thread_index * SCAN_BLOCK_LENGTH;
+
+                        while block_start < start_number_threshold {
+                            let block_end =
+                                (block_start + SCAN_BLOCK_LENGTH).min(start_number_threshold);
+
+                            for start_number in block_start..block_end {
+                                let length = self.length(start_number);
+
+                                if longest.is_none_or(|(_, longest_length)| length > longest_length)
+                                {
+                                    longest = Some((start_number, length));
+                                }
+                            }
+
+                            block_start += thread_count * SCAN_BLOCK_LENGTH;
+                        }
+
+                        longest
+                    })
+                })
+                .collect();
+
+            scans
+                .into_iter()
+                .filter_map(|scan| scan.join().expect("Collatz scan thread to finish"))
+                .max_by_key(|&(start_number, length)| (length, u64::MAX - start_number))
+        })?;
+
+        Some(LongestChain {
+            start_number,
+            length,
+            chain: Self::chain(start_number),
+        })
+    }
+
+    /// Get terms of the Collatz sequence chain of a start number, from the start number down to 1.
+    /// - **start_number:** Positive start number of the chain.
+    /// ## Notes
+    /// - Terms are stepped through in 128-bit integers, so chains that pass `u64::MAX` are found
+    ///   like their lengths are.
+    fn chain(start_number: u64) -> Vec<u128> {
+        let mut term = u128::from(start_number);
+        let mut chain = vec![term];
+
+        while term > 1 {
+            term = if term.is_multiple_of(2) {
+                term / 2
+            } else {
+                term.checked_mul(3)
+                    .and_then(|term| term.checked_add(1))
+                    .expect("Collatz term to fit in a u128")
+            };
+            chain.push(term);
+        }
+
+        chain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CollatzCache, MAX_CACHE_LIMIT};
+    use crate::shared::iterators::CollatzSequence;
+
+    #[test]
+    fn length_matches_collatz_sequence() {
+        let collatz_cache = CollatzCache::new(100);
+
+        for start_number in 1..1_000 {
+            assert_eq!(
+                collatz_cache.length(start_number),
+                CollatzSequence::new(start_number).count() as u64
+            );
+        }
+    }
+
+    #[test]
+    fn length_promotes_terms_past_u64_max() {
+        let collatz_cache = CollatzCache::new(1_000);
+        let start_number = u64::MAX;
+
+        let mut steps = 0;
+        let mut term = u128::from(start_number);
+        while term != 1 {
+            term = if term % 2 == 0 {
+                term / 2
+            } else {
+                3 * term + 1
+            };
+            steps += 1;
+        }
+
+        assert_eq!(collatz_cache.length(start_number), steps + 1);
+
+        let chain = CollatzCache::chain(start_number);
+        assert_eq!(chain.len() as u64, steps + 1);
+        assert!(chain.iter().any(|&term| term > u128::from(u64::MAX)));
+        assert_eq!(chain.last(), Some(&1));
+    }
+
+    #[test]
+    fn longest_chain_below_finds_longest_chain() {
+        let collatz_cache = CollatzCache::new(100_000);
+        let longest_chain = collatz_cache
+            .longest_chain_below(100_000)
+            .expect("longest chain to be found");
+
+        let (expected_start_number, expected_length) = (1..100_000)
+            .map(|start_number| {
+                (
+                    start_number,
+                    CollatzSequence::new(start_number).count() as u64,
+                )
+            })
+            .min_by_key(|&(start_number, length)| (u64::MAX - length, start_number))
+            .expect("start numbers to not be empty");
+
+        assert_eq!(longest_chain.start_number, expected_start_number);
+        assert_eq!(longest_chain.length, expected_length);
+        assert_eq!(longest_chain.chain.len() as u64, expected_length);
+        assert_eq!(
+            longest_chain.chain.first(),
+            Some(&u128::from(expected_start_number))
+        );
+        assert_eq!(longest_chain.chain.last(), Some(&1));
+    }
+
+    #[test]
+    fn new_caps_limit() {
+        let collatz_cache = CollatzCache::new(usize::MAX);
+
+        assert_eq!(collatz_cache.limit(), MAX_CACHE_LIMIT);
+        assert_eq!(
+            collatz_cache.length(MAX_CACHE_LIMIT as u64 + 1),
+            CollatzSequence::new(MAX_CACHE_LIMIT as u64 + 1).count() as u64
+        );
+    }
+
+    #[test]
+    fn longest_chain_below_handles_small_thresholds() {
+        let collatz_cache = CollatzCache::new(0);
+
+        assert_eq!(collatz_cache.longest_chain_below(1), None);
+        assert_eq!(
+            collatz_cache
+                .longest_chain_below(2)
+                .map(|longest_chain| longest_chain.chain),
+            Some(vec![1])
+        );
+    }
+}
@@ -0,0 +1,560 @@
+use crate::shared::math_helpers::modular::{mod_inverse, mod_mul};
+use num::{BigUint, CheckedMul, Integer, One, Zero};
+
+/// Multiply a value by a multiplier and divide by a divisor, where the division is exact.
+/// - **value:** Value to multiply.
+/// - **multiplier:** Multiplier.
+/// - **divisor:** Non-zero divisor of the value times the multiplier.
+/// ## Notes
+/// - The value is divided by its greatest common divisor with the divisor first, so only the
+///   exact result has to fit in the integer type.
+fn checked_mul_div<T>(value: T, multiplier: u64, divisor: u64) -> Option<T>
+where
+    T: CheckedMul + Clone + From<u64> + Integer,
+{
+    let divisor = T::from(divisor);
+    let common_divisor = value.gcd(&divisor);
+
+    (value / common_divisor.clone())
+        .checked_mul(&(T::from(multiplier) / (divisor / common_divisor)))
+}
+
+/// Get binomial coefficient in an integer type, if it fits.
+/// - **n:** Size of the set to choose from.
+/// - **k:** Number of elements to choose.
+/// ## Notes
+/// - **Multiplicative Formula:** C(n, k) = C(n, k - 1) × (n - k + 1) / k, where every partial
+///   product is itself a binomial coefficient no larger than the result.
+fn checked_binomial<T>(n: u64, k: u64) -> Option<T>
+where
+    T: CheckedMul + Clone + From<u64> + Integer,
+{
+    if k > n {
+        return Some(T::zero());
+    }
+
+    (0..k.min(n - k)).try_fold(T::one(), |binomial, index| {
+        checked_mul_div(binomial, n - index, index + 1)
+    })
+}
+
+/// Get multinomial coefficient in an integer type, if it fits.
+/// - **counts:** Number of elements in each group.
+/// ## Notes
+/// - **Multinomial Coefficient:** (k₁ + k₂ + ... + kₘ)! / (k₁! k₂! ... kₘ!), found as a product
+///   of binomial coefficients that each add a group.
+fn checked_multinomial<T>(counts: &[u64]) -> Option<T>
+where
+    T: CheckedMul + Clone + From<u64> + Integer,
+{
+    counts
+        .iter()
+        .try_fold((T::one(), 0_u64), |(multinomial, total), &count| {
+            let total = total.checked_add(count)?;
+            let binomial = checked_binomial::<T>(total, count)?;
+
+            Some((multinomial.checked_mul(&binomial)?, total))
+        })
+        .map(|(multinomial, _)| multinomial)
+}
+
+/// Get Catalan number of an index in an integer type, if it fits.
+/// - **index:** Index n of the Catalan number, where C(0) = 1.
+/// ## Notes
+/// - **Catalan Recurrence:** C(n + 1) = C(n) × 2(2n + 1) / (n + 2).
+fn checked_catalan<T>(index: u64) -> Option<T>
+where
+    T: CheckedMul + Clone + From<u64> + Integer,
+{
+    (0..index).try_fold(T::one(), |catalan, index| {
+        checked_mul_div(catalan, 4 * index + 2, index + 2)
+    })
+}
+
+/// Get binomial coefficient, if it fits in a `u64`.
+/// - **n:** Size of the set to choose from.
+/// - **k:** Number of elements to choose.
+/// ## Notes
+/// - **Binomial Coefficient:** C(n, k) = n! / (k!(n - k)!), the number of ways to choose k
+///   elements from n. It is 0 when k is greater than n.
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    checked_binomial(n, k)
+}
+
+/// Get binomial coefficient, if it fits in a `u128`.
+/// - **n:** Size of the set to choose from.
+/// - **k:** Number of elements to choose.
+pub fn binomial_u128(n: u64, k: u64) -> Option<u128> {
+    checked_binomial(n, k)
+}
+
+/// Get binomial coefficient as an arbitrarily large integer.
+/// - **n:** Size of the set to choose from.
+/// - **k:** Number of elements to choose.
+pub fn binomial_big(n: u64, k: u64) -> BigUint {
+    checked_binomial(n, k).expect("arbitrarily large integers to not overflow")
+}
+
+/// Get binomial coefficient modulo a prime, using Lucas's theorem.
+/// - **n:** Size of the set to choose from.
+/// - **k:** Number of elements to choose.
+/// - **prime:** Prime modulus, small enough to tabulate factorials below it.
+/// ## Notes
+/// - See `FactorialTable::binomial` to answer many queries with the same table.
+pub fn binomial_mod(n: u64, k: u64, prime: u64) -> u64 {
+    let limit = usize::try_from(n.min(prime - 1)).expect("factorial table to fit in memory");
+
+    FactorialTable::new(limit, prime).binomial(n, k)
+}
+
+/// Get multinomial coefficient, if it fits in a `u64`.
+/// - **counts:** Number of elements in each group.
+/// ## Notes
+/// - **Multinomial Coefficient:** The number of ways to split k₁ + k₂ + ... + kₘ elements into
+///   groups of k₁, k₂, ..., kₘ elements.
+pub fn multinomial(counts: &[u64]) -> Option<u64> {
+    checked_multinomial(counts)
+}
+
+/// Get multinomial coefficient as an arbitrarily large integer.
+/// - **counts:** Number of elements in each group.
+pub fn multinomial_big(counts: &[u64]) -> BigUint {
+    checked_multinomial(counts).expect("arbitrarily large integers to not overflow")
+}
+
+/// Get Catalan number of an index, if it fits in a `u64`.
+/// - **index:** Index n of the Catalan number, where C(0) = 1.
+/// ## Notes
+/// - **Catalan Number:** C(n) = C(2n, n) / (n + 1), which counts balanced bracket sequences,
+///   binary trees and monotonic lattice paths that stay below the diagonal, among others.
+/// - C(36) is the largest Catalan number that fits in a `u64`.
+pub fn catalan(index: u64) -> Option<u64> {
+    checked_catalan(index)
+}
+
+/// Get Catalan number of an index as an arbitrarily large integer.
+/// - **index:** Index n of the Catalan number, where C(0) = 1.
+pub fn catalan_big(index: u64) -> BigUint {
+    checked_catalan(index).expect("arbitrarily large integers to not overflow")
+}
+
+/// Get unsigned Stirling number of the first kind.
+/// - **n:** Number of elements.
+/// - **k:** Number of cycles.
+/// ## Notes
+/// - **Stirling Number of the First Kind:** [n, k], the number of permutations of n elements
+///   with exactly k cycles, found row by row with [n + 1, k] = n[n, k] + [n, k - 1].
+pub fn stirling_first(n: u64, k: u64) -> BigUint {
+    stirling_number(n, k, |row, _| BigUint::from(row))
+}
+
+/// Get Stirling number of the second kind.
+/// - **n:** Number of elements.
+/// - **k:** Number of subsets.
+/// ## Notes
+/// - **Stirling Number of the Second Kind:** {n, k}, the number of ways to split n elements into
+///   exactly k non-empty subsets, found row by row with {n + 1, k} = k{n, k} + {n, k - 1}.
+pub fn stirling_second(n: u64, k: u64) -> BigUint {
+    stirling_number(n, k, |_, column| BigUint::from(column))
+}
+
+/// Get Stirling number by filling rows of the triangle up to a column.
+/// - **n:** Row of the triangle.
+/// - **k:** Column of the triangle.
+/// - **weight:** Weight of the term above, given the row and column.
+/// ## Notes
+/// - Each term is the weighted term above plus the term above and to the left.
+fn stirling_number(n: u64, k: u64, weight: impl Fn(u64, u64) -> BigUint) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+
+    let columns = usize::try_from(k).expect("Stirling triangle columns to fit in memory") + 1;
+    let mut row_terms = vec![BigUint::zero(); columns];
+    row_terms[0] = BigUint::one();
+
+    for row in 0..n {
+        for column in (1..columns).rev() {
+            row_terms[column] =
+                weight(row, column as u64) * &row_terms[column] + &row_terms[column - 1];
+        }
+
+        row_terms[0] = BigUint::zero();
+    }
+
+    row_terms.swap_remove(columns - 1)
+}
+
+/// Get Bell number of an index.
+/// - **index:** Index n of the Bell number, where B(0) = 1.
+/// ## Notes
+/// - **Bell Number:** B(n), the number of ways to split n elements into non-empty subsets.
+/// - **Bell Triangle:** Each row starts with the last term of the row before it, and each
+///   following term adds the term above and to the left. Rows start with the Bell numbers.
+pub fn bell(index: u64) -> BigUint {
+    let mut row_terms = vec![BigUint::one()];
+
+    for _ in 0..index {
+        let mut next_row_terms = Vec::with_capacity(row_terms.len() + 1);
+        next_row_terms.push(row_terms[row_terms.len() - 1].clone());
+
+        for term in &row_terms {
+            let next_term = &next_row_terms[next_row_terms.len() - 1] + term;
+            next_row_terms.push(next_term);
+        }
+
+        row_terms = next_row_terms;
+    }
+
+    row_terms.swap_remove(0)
+}
+
+/// Rearrange elements into the next permutation in lexicographic order.
+/// - **elements:** Elements to rearrange.
+/// ## Notes
+/// - **Narayana's Algorithm:** Finds the rightmost element smaller than the one after it, swaps
+///   it with the rightmost element larger than it, then reverses the elements after it.
+/// - Returns `false` and rearranges the elements into the first, sorted, permutation if they are
+///   already in the last.
+pub fn next_permutation<T: Ord>(elements: &mut [T]) -> bool {
+    let Some(pivot) = (1..elements.len())
+        .rev()
+        .find(|&index| elements[index - 1] < elements[index])
+        .map(|index| index - 1)
+    else {
+        elements.reverse();
+        return false;
+    };
+
+    let successor = (pivot + 1..elements.len())
+        .rev()
+        .find(|&index| elements[index] > elements[pivot])
+        .expect("an element after the pivot to be larger than it");
+    elements.swap(pivot, successor);
+    elements[pivot + 1..].reverse();
+
+    true
+}
+
+/// Get index of a permutation of distinct elements in lexicographic order, if it fits in a `u64`.
+/// - **permutation:** Permutation of distinct elements.
+/// ## Notes
+/// - **Factorial Number System:** The digit of each position is the number of later elements that
+///   are smaller than it, with place value (n - 1 - position)!.
+pub fn permutation_rank<T: Ord>(permutation: &[T]) -> Option<u64> {
+    permutation
+        .iter()
+        .enumerate()
+        .try_fold(0_u64, |rank, (position, element)| {
+            let digit = permutation[position + 1..]
+                .iter()
+                .filter(|&later_element| later_element < element)
+                .count() as u64;
+
+            rank.checked_mul((permutation.len() - position) as u64)?
+                .checked_add(digit)
+        })
+}
+
+/// Get permutation of an index in lexicographic order of distinct elements.
+/// - **elements:** Distinct elements to permute, in any order.
+/// - **index:** Index of the permutation, where the sorted permutation is 0.
+/// ## Notes
+/// - **Factorial Number System:** Each digit of the index, from place value (n - 1)! down to 0!,
+///   picks an element from those not yet placed.
+/// - Returns `None` if the index is not less than n!.
+pub fn nth_permutation<T: Clone + Ord>(elements: &[T], index: u64) -> Option<Vec<T>> {
+    let mut remaining_elements = elements.to_vec();
+    remaining_elements.sort();
+
+    let mut index = index;
+    let mut permutation = Vec::with_capacity(elements.len());
+
+    while !remaining_elements.is_empty() {
+        let place_value = (1..remaining_elements.len() as u64)
+            .try_fold(1_u64, |factorial, number| factorial.checked_mul(number));
+        let digit = match place_value {
+            Some(place_value) => {
+                let digit = index / place_value;
+                index %= place_value;
+                usize::try_from(digit).ok()?
+            }
+            None => 0,
+        };
+
+        if digit >= remaining_elements.len() {
+            return None;
+        }
+        permutation.push(remaining_elements.remove(digit));
+    }
+
+    (index == 0).then_some(permutation)
+}
+
+/// Factorials and their inverses modulo a prime, for answering many combinatorial queries.
+/// ## Notes
+/// - Inverse factorials are found with one modular inverse, from the largest factorial down, as
+///   1 / k! = (k + 1) / (k + 1)!.
+pub struct FactorialTable {
+    /// Prime modulus.
+    prime: u64,
+
+    /// Factorial of each number up to the limit, modulo the prime.
+    factorials: Vec<u64>,
+
+    /// Inverse of the factorial of each number up to the limit, modulo the prime.
+    inverse_factorials: Vec<u64>,
+}
+
+impl FactorialTable {
+    /// Create a table of factorials up to a limit.
+    /// - **limit:** Inclusive upper bound of the numbers, less than the prime.
+    /// - **prime:** Prime modulus.
+    pub fn new(limit: usize, prime: u64) -> Self {
+        assert!(
+            (limit as u64) < prime,
+            "factorials up to the limit must be invertible modulo the prime"
+        );
+
+        let mut factorials = vec![1 % prime; limit + 1];
+        for number in 1..=limit {
+            factorials[number] = mod_mul(factorials[number - 1], number as u64, prime);
+        }
+
+        let mut inverse_factorials = vec![0; limit + 1];
+        inverse_factorials[limit] =
+            mod_inverse(factorials[limit], prime).expect("modulus to be prime");
+        for number in (1..=limit).rev() {
+            inverse_factorials[number - 1] =
+                mod_mul(inverse_factorials[number], number as u64, prime);
+        }
+
+        Self {
+            prime,
+            factorials,
+            inverse_factorials,
+        }
+    }
+
+    /// Get inclusive upper bound of the numbers with tabulated factorials.
+    pub fn limit(&self) -> usize {
+        self.factorials.len() - 1
+    }
+
+    /// Get prime modulus.
+    pub fn prime(&self) -> u64 {
+        self.prime
+    }
+
+    /// Get factorial of a number up to the limit, modulo the prime.
+    /// - **number:** Number to get the factorial of.
+    pub fn factorial(&self, number: usize) -> u64 {
+        self.factorials[number]
+    }
+
+    /// Get inverse of the factorial of a number up to the limit, modulo the prime.
+    /// - **number:** Number to get the inverse factorial of.
+    pub fn inverse_factorial(&self, number: usize) -> u64 {
+        self.inverse_factorials[number]
+    }
+
+    /// Get binomial coefficient of numbers up to the limit, modulo the prime.
+    /// - **n:** Size of the set to choose from, up to the limit.
+    /// - **k:** Number of elements to choose.
+    fn tabulated_binomial(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+
+        mod_mul(
+            mod_mul(self.factorials[n], self.inverse_factorials[k], self.prime),
+            self.inverse_factorials[n - k],
+            self.prime,
+        )
+    }
+
+    /// Get binomial coefficient modulo the prime.
+    /// - **n:** Size of the set to choose from.
+    /// - **k:** Number of elements to choose.
+    /// ## Notes
+    /// - **Lucas's Theorem:** C(n, k) is congruent to the product of the binomial coefficients of
+    ///   the base-p digits of n and k. Used when n is above the limit, which must then be p - 1.
+    pub fn binomial(&self, n: u64, k: u64) -> u64 {
+        if let Ok(n) = usize::try_from(n) {
+            if n <= self.limit() {
+                return self.tabulated_binomial(n, usize::try_from(k).unwrap_or(usize::MAX));
+            }
+        }
+
+        assert_eq!(
+            self.limit() as u64,
+            self.prime - 1,
+            "factorial table must reach p - 1 to use Lucas's theorem"
+        );
+
+        let (mut n, mut k) = (n, k);
+        let mut binomial = 1 % self.prime;
+
+        while k > 0 && binomial > 0 {
+            let digit_binomial =
+                self.tabulated_binomial((n % self.prime) as usize, (k % self.prime) as usize);
+            binomial = mod_mul(binomial, digit_binomial, self.prime);
+            n /= self.prime;
+            k /= self.prime;
+        }
+
+        binomial
+    }
+
+    /// Get multinomial coefficient modulo the prime.
+    /// - **counts:** Number of elements in each group.
+    pub fn multinomial(&self, counts: &[u64]) -> u64 {
+        let mut total: u64 = 0;
+
+        counts.iter().fold(1 % self.prime, |multinomial, &count| {
+            total = total
+                .checked_add(count)
+                .expect("multinomial total to fit in a u64");
+
+            mod_mul(multinomial, self.binomial(total, count), self.prime)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bell, binomial, binomial_big, binomial_mod, binomial_u128, catalan, catalan_big,
+        multinomial, multinomial_big, stirling_first, stirling_second, FactorialTable,
+    };
+    use num::{BigUint, ToPrimitive};
+
+    #[test]
+    fn binomial_matches_pascals_triangle() {
+        let mut row: Vec<u128> = vec![1];
+
+        for n in 0..=130_u64 {
+            for (k, &term) in row.iter().enumerate() {
+                assert_eq!(binomial_u128(n, k as u64), Some(term));
+                assert_eq!(binomial_big(n, k as u64), BigUint::from(term));
+                assert_eq!(binomial(n, k as u64), u64::try_from(term).ok());
+            }
+            assert_eq!(binomial(n, n + 1), Some(0));
+
+            row = (0..=row.len())
+                .map(|k| {
+                    let left = k.checked_sub(1).map_or(0, |k| row[k]);
+                    left + row.get(k).copied().unwrap_or(0)
+                })
+                .collect();
+        }
+    }
+
+    #[test]
+    fn binomial_only_overflows_when_result_does_not_fit() {
+        assert_eq!(binomial(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(binomial(68, 34), None);
+        assert_eq!(binomial(u64::MAX, 1), Some(u64::MAX));
+        assert_eq!(binomial(u64::MAX, u64::MAX - 1), Some(u64::MAX));
+        assert_eq!(binomial_big(1_000, 500).to_string().len(), 300);
+    }
+
+    #[test]
+    fn binomial_mod_matches_big_binomial() {
+        let primes = [2, 3, 7, 1_009];
+        let factorial_tables = primes.map(|prime| FactorialTable::new(prime as usize - 1, prime));
+
+        for n in [0, 1, 5, 100, 1_008, 1_009, 3_000] {
+            for k in [0, 1, 3, 50, 1_000, 2_017] {
+                let big_binomial = binomial_big(n, k);
+
+                for (prime, factorial_table) in primes.iter().zip(&factorial_tables) {
+                    let expected = (&big_binomial % prime)
+                        .to_u64()
+                        .expect("remainder to fit in a u64");
+
+                    assert_eq!(binomial_mod(n, k, *prime), expected);
+                    assert_eq!(factorial_table.binomial(n, k), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn factorial_table_inverts_factorials() {
+        let factorial_table = FactorialTable::new(1_000, 1_000_000_007);
+
+        assert_eq!(factorial_table.factorial(20), 146_326_063);
+        for number in [0, 1, 500, 1_000] {
+            assert_eq!(
+                u128::from(factorial_table.factorial(number))
+                    * u128::from(factorial_table.inverse_factorial(number))
+                    % 1_000_000_007,
+                1
+            );
+        }
+        assert_eq!(
+            factorial_table.multinomial(&[3, 4, 5]),
+            multinomial(&[3, 4, 5]).expect("multinomial to fit in a u64") % 1_000_000_007
+        );
+    }
+
+    #[test]
+    fn multinomial_counts_arrangements() {
+        assert_eq!(multinomial(&[]), Some(1));
+        assert_eq!(multinomial(&[1, 4, 4, 2]), Some(34_650));
+        assert_eq!(multinomial(&[5]), Some(1));
+        assert_eq!(multinomial(&[30, 30, 30]), None);
+        assert_eq!(
+            multinomial_big(&[30, 30, 30]),
+            binomial_big(90, 30) * binomial_big(60, 30)
+        );
+    }
+
+    #[test]
+    fn catalan_returns_catalan_numbers() {
+        assert_eq!(
+            (0..10).map(catalan).collect::<Vec<Option<u64>>>(),
+            [1, 1, 2, 5, 14, 42, 132, 429, 1_430, 4_862].map(Some)
+        );
+        assert_eq!(catalan(36), Some(11_959_798_385_860_453_492));
+        assert_eq!(catalan(37), None);
+        assert_eq!(
+            catalan_big(100),
+            binomial_big(200, 100) / BigUint::from(101_u64)
+        );
+    }
+
+    #[test]
+    fn stirling_and_bell_numbers_agree() {
+        assert_eq!(
+            (0..=5)
+                .map(|k| stirling_first(5, k))
+                .collect::<Vec<BigUint>>(),
+            [0_u64, 24, 50, 35, 10, 1].map(BigUint::from)
+        );
+        assert_eq!(
+            (0..=5)
+                .map(|k| stirling_second(5, k))
+                .collect::<Vec<BigUint>>(),
+            [0_u64, 1, 15, 25, 10, 1].map(BigUint::from)
+        );
+        assert_eq!(stirling_first(0, 0), BigUint::from(1_u64));
+        assert_eq!(stirling_second(3, 4), BigUint::from(0_u64));
+        assert_eq!(
+            (0..10).map(bell).collect::<Vec<BigUint>>(),
+            [1_u64, 1, 2, 5, 15, 52, 203, 877, 4_140, 21_147].map(BigUint::from)
+        );
+
+        for n in [0, 7, 30] {
+            assert_eq!(
+                (0..=n).map(|k| stirling_second(n, k)).sum::<BigUint>(),
+                bell(n)
+            );
+            assert_eq!(
+                (0..=n).map(|k| stirling_first(n, k)).sum::<BigUint>(),
+                (1..=n).map(BigUint::from).product::<BigUint>()
+            );
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use num::{BigUint, CheckedAdd, CheckedMul, One, Zero};
+use std::vec;
+
+/// Digit extension trait for unsigned integer types, in any base from 2 to 256.
+/// ## Notes
+/// - Digits are `u32` values below the base. Zero has the single digit 0, and no other number has
+///   leading zeros.
+pub trait Digits: Clone + CheckedAdd + CheckedMul + From<u8> + One + Zero {
+    /// Iterate through digits, from least to most significant. Reverse the iterator to go from most
+    /// to least significant.
+    /// - **base:** Base of the digits.
+    fn digits(&self, base: u32) -> DigitIterator;
+
+    /// Get number from its digits, if it fits in the integer type.
+    /// - **digits:** Digits, from most to least significant.
+    /// - **base:** Base of the digits.
+    fn from_digits<I: IntoIterator<Item = u32>>(digits: I, base: u32) -> Option<Self> {
+        assert_valid_base(base);
+        let base_value = Self::from((base - 1) as u8).checked_add(&Self::one());
+
+        digits.into_iter().try_fold(Self::zero(), |number, digit| {
+            assert!(digit < base, "digit must be less than the base");
+
+            let shifted_number = if number.is_zero() {
+                number
+            } else {
+                number.checked_mul(base_value.as_ref()?)?
+            };
+            shifted_number.checked_add(&Self::from(digit as u8))
+        })
+    }
+
+    /// Get number of digits.
+    /// - **base:** Base of the digits.
+    fn digit_count(&self, base: u32) -> usize {
+        self.digits(base).len()
+    }
+
+    /// Get sum of the digits.
+    /// - **base:** Base of the digits.
+    fn digit_sum(&self, base: u32) -> u64 {
+        self.digits(base).map(u64::from).sum()
+    }
+
+    /// Get product of the digits.
+    /// - **base:** Base of the digits.
+    /// ## Notes
+    /// - The product of the digits is never larger than the number, so it always fits.
+    fn digit_product(&self, base: u32) -> Self {
+        self.digits(base)
+            .try_fold(Self::one(), |product, digit| {
+                product.checked_mul(&Self::from(digit as u8))
+            })
+            .expect("digit product to be no larger than the number")
+    }
+
+    /// Get number with its digits reversed, if it fits in the integer type.
+    /// - **base:** Base of the digits.
+    /// ## Notes
+    /// - Trailing zeros become leading zeros and are dropped, so 120 reverses to 21.
+    fn reverse_digits(&self, base: u32) -> Option<Self> {
+        Self::from_digits(self.digits(base), base)
+    }
+
+    /// Get number with its digits rotated left, if it fits in the integer type.
+    /// - **base:** Base of the digits.
+    /// - **places:** Number of most significant digits to move to the end.
+    /// ## Notes
+    /// - Zeros rotated to the front become leading zeros and are dropped, so 102 rotated by one
+    ///   place is 21.
+    fn rotate_digits(&self, base: u32, places: usize) -> Option<Self> {
+        let mut digits: Vec<u32> = self.digits(base).rev().collect();
+        let digit_count = digits.len();
+        digits.rotate_left(places % digit_count);
+
+        Self::from_digits(digits, base)
+    }
+
+    /// Get number with the digits of another number appended, if it fits in the integer type.
+    /// - **other:** Number whose digits to append.
+    /// - **base:** Base of the digits.
+    fn concat_digits(&self, other: &Self, base: u32) -> Option<Self> {
+        Self::from_digits(
+            self.digits(base).rev().chain(other.digits(base).rev()),
+            base,
+        )
+    }
+
+    /// Get number of times each digit appears, indexed by digit.
+    /// - **base:** Base of the digits.
+    /// ## Notes
+    /// - **Digit Signature:** Two numbers are digit permutations of each other exactly when their
+    ///   signatures are equal.
+    fn digit_signature(&self, base: u32) -> Vec<u32> {
+        let mut signature = vec![0; base as usize];
+
+        for digit in self.digits(base) {
+            signature[digit as usize] += 1;
+        }
+
+        signature
+    }
+
+    /// Check if another number has the same digits in some order.
+    /// - **other:** Number to compare digits with.
+    /// - **base:** Base of the digits.
+    fn is_digit_permutation(&self, other: &Self, base: u32) -> bool {
+        self.digit_signature(base) == other.digit_signature(base)
+    }
+}
+
+/// Check that a base is supported.
+/// - **base:** Base of the digits.
+fn assert_valid_base(base: u32) {
+    assert!((2..=256).contains(&base), "base must be between 2 and 256");
+}
+
+/// Implement the digits extension trait for primitive unsigned integer types.
+macro_rules! impl_digits {
+    ($($integer_type:ty),*) => {
+        $(
+            impl Digits for $integer_type {
+                fn digits(&self, base: u32) -> DigitIterator {
+                    DigitIterator::primitive(*self as u128, base)
+                }
+            }
+        )*
+    };
+}
+
+impl_digits!(u8, u16, u32, u64, u128, usize);
+
+impl Digits for BigUint {
+    fn digits(&self, base: u32) -> DigitIterator {
+        assert_valid_base(base);
+
+        DigitIterator {
+            digits: RemainingDigits::Big(self.to_radix_le(base).into_iter()),
+        }
+    }
+}
+
+/// Digits of a number yet to be returned.
+enum RemainingDigits {
+    /// Digits of a primitive integer, found by division as they are returned.
+    Primitive {
+        /// Number made of the digits yet to be returned.
+        remaining: u128,
+
+        /// Place value of the most significant digit yet to be returned.
+        high_place_value: u128,
+
+        /// Base of the digits.
+        base: u128,
+
+        /// Number of digits yet to be returned.
+        count: usize,
+    },
+
+    /// Digits of an arbitrarily large integer, found up front, from least to most significant.
+    Big(vec::IntoIter<u8>),
+}
+
+/// Digits iterator, from least to most significant, or the reverse from either end.
+pub struct DigitIterator {
+    /// Digits yet to be returned.
+    digits: RemainingDigits,
+}
+
+impl DigitIterator {
+    /// Iterate through the digits of a primitive integer.
+    /// - **number:** Number to get the digits of.
+    /// - **base:** Base of the digits.
+    fn primitive(number: u128, base: u32) -> Self {
+        assert_valid_base(base);
+        let base = u128::from(base);
+        let mut high_place_value = 1;
+        let mut count = 1;
+
+        while number / high_place_value >= base {
+            high_place_value *= base;
+            count += 1;
+        }
+
+        Self {
+            digits: RemainingDigits::Primitive {
+                remaining: number,
+                high_place_value,
+                base,
+                count,
+            },
+        }
+    }
+}
+
+impl Iterator for DigitIterator {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.digits {
+            RemainingDigits::Primitive {
+                remaining,
+                high_place_value,
+                base,
+                count,
+            } => {
+                *count = count.checked_sub(1)?;
+                let digit = *remaining % *base;
+                *remaining /= *base;
+                *high_place_value /= *base;
+
+                Some(digit as u32)
+            }
+            RemainingDigits::Big(digits) => digits.next().map(u32::from),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = match &self.digits {
+            RemainingDigits::Primitive { count, .. } => *count,
+            RemainingDigits::Big(digits) => digits.len(),
+        };
+
+        (length, Some(length))
+    }
+}
+
+impl DoubleEndedIterator for DigitIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.digits {
+            RemainingDigits::Primitive {
+                remaining,
+                high_place_value,
+                base,
+                count,
+            } => {
+                *count = count.checked_sub(1)?;
+                let digit = *remaining / *high_place_value;
+                *remaining %= *high_place_value;
+                *high_place_value /= *base;
+
+                Some(digit as u32)
+            }
+            RemainingDigits::Big(digits) => digits.next_back().map(u32::from),
+        }
+    }
+}
+
+impl ExactSizeIterator for DigitIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::Digits;
+    use num::BigUint;
+
+    #[test]
+    fn digits_iterates_from_either_end() {
+        assert_eq!(1_002_u64.digits(10).collect::<Vec<u32>>(), vec![2, 0, 0, 1]);
+        assert_eq!(
+            1_002_u64.digits(10).rev().collect::<Vec<u32>>(),
+            vec![1, 0, 0, 2]
+        );
+        assert_eq!(0_u64.digits(10).collect::<Vec<u32>>(), vec![0]);
+        assert_eq!(
+            0xbeef_u32.digits(16).rev().collect::<Vec<u32>>(),
+            vec![11, 14, 14, 15]
+        );
+
+        let mut digits = 12_345_u64.digits(10);
+        assert_eq!(
+            (
+                digits.next(),
+                digits.next_back(),
+                digits.next(),
+                digits.len()
+            ),
+            (Some(5), Some(1), Some(4), 2)
+        );
+        assert_eq!(digits.collect::<Vec<u32>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn digits_agree_across_integer_types() {
+        for number in [0, 1, 9, 10, 255, 1_000_000, u64::MAX] {
+            for base in [2, 3, 10, 16, 256] {
+                let big_number = BigUint::from(number);
+                let wide_number = u128::from(number);
+
+                assert!(number.digits(base).eq(big_number.digits(base)));
+                assert!(number.digits(base).rev().eq(wide_number.digits(base).rev()));
+                assert_eq!(
+                    u64::from_digits(number.digits(base).rev(), base),
+                    Some(number)
+                );
+                assert_eq!(number.digit_count(base), big_number.digit_count(base));
+            }
+        }
+        assert_eq!(u128::MAX.digit_count(2), 128);
+        assert_eq!(u128::MAX.digit_count(10), 39);
+    }
+
+    #[test]
+    fn digit_sum_and_product_combine_digits() {
+        assert_eq!(9_875_u64.digit_sum(10), 29);
+        assert_eq!(9_875_u64.digit_product(10), 2_520);
+        assert_eq!(1_203_u64.digit_product(10), 0);
+        assert_eq!(0b1011_u8.digit_sum(2), 3);
+        assert_eq!(BigUint::from(2_u8).pow(100).digit_sum(10), 115);
+        assert_eq!(u64::MAX.digit_product(10), 0);
+        assert_eq!(
+            99_999_999_999_999_999_999_u128.digit_product(10),
+            9_u128.pow(20)
+        );
+    }
+
+    #[test]
+    fn from_digits_rejects_numbers_that_do_not_fit() {
+        assert_eq!(u8::from_digits([2, 5, 5], 10), Some(255));
+        assert_eq!(u8::from_digits([2, 5, 6], 10), None);
+        assert_eq!(u8::from_digits([0, 255], 256), Some(255));
+        assert_eq!(u8::from_digits([1, 0], 256), None);
+        assert_eq!(u64::from_digits([], 10), Some(0));
+        assert_eq!(
+            BigUint::from_digits([1, 0, 0], 256),
+            Some(BigUint::from(65_536_u32))
+        );
+    }
+
+    #[test]
+    fn digits_rearrange() {
+        assert_eq!(1_230_u64.reverse_digits(10), Some(321));
+        assert_eq!(18_446_744_073_709_551_615_u64.reverse_digits(10), None);
+        assert_eq!(
+            BigUint::from(u64::MAX).reverse_digits(10),
+            Some(BigUint::from(51_615_590_737_044_764_481_u128))
+        );
+        assert_eq!(142_857_u64.rotate_digits(10, 1), Some(428_571));
+        assert_eq!(142_857_u64.rotate_digits(10, 8), Some(285_714));
+        assert_eq!(102_u64.rotate_digits(10, 1), Some(21));
+        assert_eq!(12_u64.concat_digits(&345, 10), Some(12_345));
+        assert_eq!(0b101_u8.concat_digits(&0b11, 2), Some(0b10111));
+        assert_eq!(u64::MAX.concat_digits(&1, 10), None);
+    }
+
+    #[test]
+    fn digit_signature_checks_permutations() {
+        assert_eq!(
+            1_487_u64.digit_signature(10),
+            vec![0, 1, 0, 0, 1, 0, 0, 1, 1, 0]
+        );
+        assert!(125_874_u64.is_digit_permutation(&251_748, 10));
+        assert!(!125_874_u64.is_digit_permutation(&125_875, 10));
+        assert!(!10_u64.is_digit_permutation(&1, 10));
+    }
+}
@@ -1,8 +1,8 @@
 /// Represents an exponent.
-#[derive(Debug, Eq, PartialEq)]
-pub struct Exponent {
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Exponent<T = u64> {
     /// Base value.
-    pub base_value: u64,
+    pub base_value: T,
 
     /// Power to raise base value by.
     pub power: u64,
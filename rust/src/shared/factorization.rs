@@ -0,0 +1,439 @@
+use crate::shared::{math_helpers::prime_factors, Exponent};
+use num::BigUint;
+use std::{cmp::Ordering, ops::Mul};
+
+/// Represents a positive integer as its prime factorization, with prime powers sorted by base
+/// value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Factorization {
+    /// Prime powers, in ascending order of base value, with non-zero powers.
+    prime_powers: Vec<Exponent>,
+}
+
+impl Factorization {
+    /// Factorize input number.
+    /// - **input_number:** Positive input number to factorize.
+    /// ## Notes
+    /// - Panics if the input number is zero, which has no prime factorization.
+    pub fn new(input_number: u64) -> Self {
+        assert!(input_number > 0, "0 has no prime factorization");
+
+        Self {
+            prime_powers: prime_factors(input_number),
+        }
+    }
+
+    /// Build a factorization from prime powers, merging repeated base values.
+    /// - **prime_powers:** Prime powers, with prime base values in any order.
+    pub fn from_prime_powers(prime_powers: impl IntoIterator<Item = Exponent>) -> Self {
+        let mut sorted_prime_powers: Vec<Exponent> = prime_powers
+            .into_iter()
+            .filter(|prime_power| prime_power.power > 0)
+            .collect();
+        sorted_prime_powers.sort_by_key(|prime_power| prime_power.base_value);
+
+        let mut merged_prime_powers: Vec<Exponent> = vec![];
+        for prime_power in sorted_prime_powers {
+            match merged_prime_powers.last_mut() {
+                Some(last) if last.base_value == prime_power.base_value => {
+                    last.power += prime_power.power
+                }
+                _ => merged_prime_powers.push(prime_power),
+            }
+        }
+
+        Self {
+            prime_powers: merged_prime_powers,
+        }
+    }
+
+    /// Get prime powers, in ascending order of base value.
+    pub fn prime_powers(&self) -> &[Exponent] {
+        &self.prime_powers
+    }
+
+    /// Combine prime powers of two factorizations, keeping the base values with a non-zero power.
+    /// - **other:** Factorization to combine with.
+    /// - **combine_powers:** Gets the combined power from the powers in each factorization.
+    fn combine(&self, other: &Self, combine_powers: impl Fn(u64, u64) -> u64) -> Self {
+        let mut prime_powers = vec![];
+        let (mut left, mut right) = (0, 0);
+
+        while left < self.prime_powers.len() || right < other.prime_powers.len() {
+            let (base_value, left_power, right_power) =
+                match (self.prime_powers.get(left), other.prime_powers.get(right)) {
+                    (Some(left_prime_power), Some(right_prime_power)) => {
+                        match left_prime_power
+                            .base_value
+                            .cmp(&right_prime_power.base_value)
+                        {
+                            Ordering::Less => {
+                                left += 1;
+                                (left_prime_power.base_value, left_prime_power.power, 0)
+                            }
+                            Ordering::Greater => {
+                                right += 1;
+                                (right_prime_power.base_value, 0, right_prime_power.power)
+                            }
+                            Ordering::Equal => {
+                                left += 1;
+                                right += 1;
+                                (
+                                    left_prime_power.base_value,
+                                    left_prime_power.power,
+                                    right_prime_power.power,
+                                )
+                            }
+                        }
+                    }
+                    (Some(left_prime_power), None) => {
+                        left += 1;
+                        (left_prime_power.base_value, left_prime_power.power, 0)
+                    }
+                    (None, Some(right_prime_power)) => {
+                        right += 1;
+                        (right_prime_power.base_value, 0, right_prime_power.power)
+                    }
+                    (None, None) => unreachable!("loop to stop once both are exhausted"),
+                };
+
+            let power = combine_powers(left_power, right_power);
+            if power > 0 {
+                prime_powers.push(Exponent { base_value, power });
+            }
+        }
+
+        Self { prime_powers }
+    }
+
+    /// Divide by another factorization, if it divides evenly.
+    /// - **divisor:** Factorization to divide by.
+    pub fn checked_div(&self, divisor: &Self) -> Option<Self> {
+        let divides_evenly = divisor.prime_powers.iter().all(|divisor_prime_power| {
+            self.prime_powers.iter().any(|prime_power| {
+                prime_power.base_value == divisor_prime_power.base_value
+                    && prime_power.power >= divisor_prime_power.power
+            })
+        });
+
+        divides_evenly.then(|| self.combine(divisor, |power, divisor_power| power - divisor_power))
+    }
+
+    /// Get greatest common divisor with another factorization.
+    /// - **other:** Factorization to get greatest common divisor with.
+    pub fn gcd(&self, other: &Self) -> Self {
+        self.combine(other, u64::min)
+    }
+
+    /// Get least common multiple with another factorization.
+    /// - **other:** Factorization to get least common multiple with.
+    pub fn lcm(&self, other: &Self) -> Self {
+        self.combine(other, u64::max)
+    }
+
+    /// Raise factorization to a power.
+    /// - **power:** Power to raise the factorization to.
+    /// ## Notes
+    /// - Panics if a prime power does not fit in a `u64`, see `checked_pow`.
+    pub fn pow(&self, power: u64) -> Self {
+        self.checked_pow(power)
+            .expect("prime powers of factorization to fit in a u64")
+    }
+
+    /// Raise factorization to a power, if every prime power fits in a `u64`.
+    /// - **power:** Power to raise the factorization to.
+    pub fn checked_pow(&self, power: u64) -> Option<Self> {
+        let prime_powers = self
+            .prime_powers
+            .iter()
+            .map(|prime_power| {
+                Some(Exponent {
+                    base_value: prime_power.base_value,
+                    power: prime_power.power.checked_mul(power)?,
+                })
+            })
+            .collect::<Option<Vec<Exponent>>>()?;
+
+        Some(Self::from_prime_powers(prime_powers))
+    }
+
+    /// Get factorized number, if it fits in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.prime_powers
+            .iter()
+            .try_fold(1_u64, |product, prime_power| {
+                u32::try_from(prime_power.power)
+                    .ok()
+                    .and_then(|power| prime_power.base_value.checked_pow(power))
+                    .and_then(|value| product.checked_mul(value))
+            })
+    }
+
+    /// Get factorized number as an arbitrarily large integer.
+    pub fn to_biguint(&self) -> BigUint {
+        self.prime_powers
+            .iter()
+            .map(|prime_power| {
+                num::pow(
+                    BigUint::from(prime_power.base_value),
+                    prime_power.power as usize,
+                )
+            })
+            .product()
+    }
+
+    /// Get number of divisors of the factorized number.
+    /// ## Notes
+    /// - **Divisor Count:** τ(n), the product of each prime power plus one.
+    /// - Panics if the count does not fit in a `u64`, which never happens for factorized numbers
+    ///   that fit in a `u64`, see `checked_divisor_count`.
+    pub fn divisor_count(&self) -> u64 {
+        self.checked_divisor_count()
+            .expect("divisor count to fit in a u64")
+    }
+
+    /// Get number of divisors of the factorized number, if it fits in a `u64`.
+    pub fn checked_divisor_count(&self) -> Option<u64> {
+        self.prime_powers
+            .iter()
+            .try_fold(1_u64, |product, prime_power| {
+                product.checked_mul(prime_power_divisor_count(prime_power.power)?)
+            })
+    }
+
+    /// Get sum of divisors of the factorized number.
+    /// ## Notes
+    /// - **Divisor Sum:** σ(n), the product of (p^(k + 1) - 1) / (p - 1) over each prime power p^k.
+    pub fn divisor_sum(&self) -> BigUint {
+        self.divisor_power_sum(1)
+    }
+
+    /// Get sum of the kth powers of the divisors of the factorized number.
+    /// - **divisor_power:** Power to raise each divisor to before summing, k.
+    /// ## Notes
+    /// - **Divisor Function:** σₖ(n), where σ₀ is the divisor count and σ₁ the divisor sum.
+    pub fn divisor_power_sum(&self, divisor_power: u32) -> BigUint {
+        self.prime_powers
+            .iter()
+            .map(|prime_power| {
+                prime_power_divisor_sum(prime_power.base_value, prime_power.power, divisor_power)
+            })
+            .product()
+    }
+
+    /// Get iterator over all divisors of the factorized number, in no particular order.
+    /// ## Notes
+    /// - Panics if the factorized number does not fit in a `u64`.
+    pub fn divisors(&self) -> Divisors {
+        assert!(
+            self.to_u64().is_some(),
+            "factorized number does not fit in a u64"
+        );
+
+        Divisors {
+            prime_powers: self.prime_powers.clone(),
+            powers: vec![0; self.prime_powers.len()],
+            current_divisor: Some(1),
+        }
+    }
+}
+
+impl Mul for Factorization {
+    type Output = Self;
+
+    /// Multiply factorizations by adding the powers of each prime.
+    /// ## Notes
+    /// - Panics if a prime power does not fit in a `u64`.
+    fn mul(self, other: Self) -> Self::Output {
+        self.combine(&other, |left_power, right_power| {
+            left_power
+                .checked_add(right_power)
+                .expect("prime powers of product to fit in a u64")
+        })
+    }
+}
+
+/// Iterator over the divisors of a factorized number.
+pub struct Divisors {
+    /// Prime powers of the factorized number.
+    prime_powers: Vec<Exponent>,
+
+    /// Power of each prime in the current divisor.
+    powers: Vec<u64>,
+
+    /// Divisor returned upon iteration.
+    current_divisor: Option<u64>,
+}
+
+impl Iterator for Divisors {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_divisor = self.current_divisor?;
+
+        self.current_divisor = None;
+        let mut next_divisor = current_divisor;
+        for (power, prime_power) in self.powers.iter_mut().zip(&self.prime_powers) {
+            if *power < prime_power.power {
+                *power += 1;
+                self.current_divisor = Some(next_divisor * prime_power.base_value);
+                break;
+            }
+
+            next_divisor /= prime_power.base_value.pow(*power as u32);
+            *power = 0;
+        }
+
+        Some(current_divisor)
+    }
+}
+
+/// Get number of divisors of a prime power, if it fits in a `u64`.
+/// - **power:** Power the prime is raised to.
+pub(crate) fn prime_power_divisor_count(power: u64) -> Option<u64> {
+    power.checked_add(1)
+}
+
+/// Get sum of the kth powers of the divisors of a prime power.
+/// - **prime:** Prime base value.
+/// - **power:** Power the prime is raised to.
+/// - **divisor_power:** Power to raise each divisor to before summing, k.
+/// ## Notes
+/// - **Geometric Series:** The kth powers of the divisors of p^e are 1, q, ..., q^e where q = p^k,
+///   summing to (q^(e + 1) - 1) / (q - 1), or e + 1 when k = 0.
+fn prime_power_divisor_sum(prime: u64, power: u64, divisor_power: u32) -> BigUint {
+    if divisor_power == 0 {
+        return BigUint::from(power) + 1_u8;
+    }
+
+    let ratio = BigUint::from(prime).pow(divisor_power);
+    (num::pow(ratio.clone(), power as usize + 1) - 1_u32) / (ratio - 1_u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Factorization;
+    use crate::shared::Exponent;
+    use num::BigUint;
+
+    #[test]
+    fn new_factorizes_into_sorted_prime_powers() {
+        assert_eq!(
+            Factorization::new(360).prime_powers(),
+            [
+                Exponent {
+                    base_value: 2,
+                    power: 3,
+                },
+                Exponent {
+                    base_value: 3,
+                    power: 2,
+                },
+                Exponent {
+                    base_value: 5,
+                    power: 1,
+                },
+            ]
+        );
+        assert!(Factorization::new(1).prime_powers().is_empty());
+    }
+
+    #[test]
+    fn from_prime_powers_merges_repeated_base_values() {
+        assert_eq!(
+            Factorization::from_prime_powers([
+                Exponent {
+                    base_value: 5,
+                    power: 1,
+                },
+                Exponent {
+                    base_value: 2,
+                    power: 1,
+                },
+                Exponent {
+                    base_value: 5,
+                    power: 2,
+                },
+                Exponent {
+                    base_value: 3,
+                    power: 0,
+                },
+            ]),
+            Factorization::new(250)
+        );
+    }
+
+    #[test]
+    fn arithmetic_matches_integer_arithmetic() {
+        let left = Factorization::new(360);
+        let right = Factorization::new(84);
+
+        assert_eq!(left.clone() * right.clone(), Factorization::new(360 * 84));
+        assert_eq!(left.gcd(&right), Factorization::new(12));
+        assert_eq!(left.lcm(&right), Factorization::new(2_520));
+        assert_eq!(left.pow(3), Factorization::new(360 * 360 * 360));
+        assert_eq!(
+            left.checked_div(&Factorization::new(45)),
+            Some(Factorization::new(8))
+        );
+        assert_eq!(left.checked_div(&right), None);
+    }
+
+    #[test]
+    fn checked_arithmetic_rejects_powers_that_do_not_fit() {
+        assert_eq!(
+            Factorization::new(4).checked_pow(u64::MAX / 2),
+            Some(Factorization::new(2).pow(u64::MAX - 1))
+        );
+        assert_eq!(Factorization::new(4).checked_pow(u64::MAX), None);
+        assert_eq!(
+            Factorization::new(2)
+                .pow(u64::MAX - 1)
+                .checked_divisor_count(),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            Factorization::new(2).pow(u64::MAX).checked_divisor_count(),
+            None
+        );
+        assert_eq!(
+            Factorization::new(6).pow(1 << 32).checked_divisor_count(),
+            None
+        );
+    }
+
+    #[test]
+    fn reconstructs_factorized_number() {
+        assert_eq!(Factorization::new(600_851_475).to_u64(), Some(600_851_475));
+        assert_eq!(Factorization::new(2).pow(64).to_u64(), None);
+        assert_eq!(
+            Factorization::new(2).pow(64).to_biguint(),
+            BigUint::from(u64::MAX) + 1_u32
+        );
+    }
+
+    #[test]
+    fn divisor_functions_match_divisors() {
+        for input_number in 1..500 {
+            let factorization = Factorization::new(input_number);
+            let mut divisors: Vec<u64> = factorization.divisors().collect();
+            divisors.sort();
+
+            assert_eq!(
+                divisors,
+                (1..=input_number)
+                    .filter(|divisor| input_number.is_multiple_of(*divisor))
+                    .collect::<Vec<u64>>()
+            );
+            assert_eq!(factorization.divisor_count(), divisors.len() as u64);
+            assert_eq!(
+                factorization.divisor_power_sum(0),
+                BigUint::from(divisors.len())
+            );
+            assert_eq!(
+                factorization.divisor_sum(),
+                BigUint::from(divisors.iter().sum::<u64>())
+            );
+        }
+    }
+}
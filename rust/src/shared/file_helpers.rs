@@ -1,4 +1,5 @@
-use std::{fmt::Debug, fs, str::FromStr};
+use crate::{Error, Result};
+use std::{fmt::Display, fs, str::FromStr};
 
 /// Create vector from input file.
 /// - **file_path:** Input file path.
@@ -6,32 +7,15 @@ use std::{fmt::Debug, fs, str::FromStr};
 ///   pattern.
 /// ## Notes
 /// - Newline characters are ignored.
-pub fn read_to_vector<T>(file_path: &str, delimiter: Option<&char>) -> Vec<T>
+pub fn read_to_vector<T>(file_path: &str, delimiter: Option<&char>) -> Result<Vec<T>>
 where
     T: FromStr + From<u32>,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Display,
 {
-    let file = fs::read_to_string(file_path)
-        .expect("file to exist and be readable")
-        .replace('\r', "");
-    let file = file.trim_end_matches('\n');
-
-    match delimiter {
-        Some(delimiter) => file
-            .replace('\n', &delimiter.to_string())
-            .split(*delimiter)
-            .map(|i| i.parse().expect("all words to be integers"))
-            .collect(),
-        None => file
-            .chars()
-            .filter(|char| *char != '\n')
-            .map(|char| {
-                char.to_digit(10)
-                    .expect("all characters to be base 10 digits")
-                    .into()
-            })
-            .collect(),
-    }
+    Ok(read_to_grid_of(file_path, delimiter)?
+        .into_iter()
+        .flatten()
+        .collect())
 }
 
 /// Create grid (2D vector) from input file.
@@ -40,40 +24,75 @@ where
 ///   pattern.
 /// ## Notes
 /// - Newline and end-of-file characters are treated as row terminators.
-pub fn read_to_grid(file_path: &str, delimiter: Option<&char>) -> Vec<Vec<u64>> {
+pub fn read_to_grid(file_path: &str, delimiter: Option<&char>) -> Result<Vec<Vec<u64>>> {
+    read_to_grid_of(file_path, delimiter)
+}
+
+/// Create grid (2D vector) of parsed values from input file.
+/// - **file_path:** Input file path.
+/// - **delimiter:** If None separate file by character, otherwise split file by specified delimiter
+///   pattern.
+fn read_to_grid_of<T>(file_path: &str, delimiter: Option<&char>) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr + From<u32>,
+    <T as FromStr>::Err: Display,
+{
     let file = fs::read_to_string(file_path)
-        .expect("file to exist and be readable")
+        .map_err(|source| Error::Io {
+            file_path: file_path.to_string(),
+            source,
+        })?
         .replace('\r', "");
     let file = file.trim_end_matches('\n').split('\n');
 
-    match delimiter {
-        Some(delimiter) => file
-            .map(|line| {
+    let parse_error = |line_index: usize, char_index: usize, message: String| Error::Parse {
+        file_path: file_path.to_string(),
+        line: line_index + 1,
+        column: char_index + 1,
+        message,
+    };
+
+    file.enumerate()
+        .map(|(line_index, line)| match delimiter {
+            Some(delimiter) => {
+                let mut char_index = 0;
+
                 line.split(*delimiter)
-                    .map(|i| i.parse().expect("all cells to be integers"))
-                    .collect()
-            })
-            .collect(),
-        None => file
-            .map(|line| {
-                line.chars()
-                    .map(|char| {
-                        char.to_digit(10)
-                            .expect("all characters to be base 10 digits")
-                            .into()
+                    .map(|word| {
+                        let word_char_index = char_index;
+                        char_index += word.chars().count() + 1;
+
+                        word.parse().map_err(|err| {
+                            parse_error(
+                                line_index,
+                                word_char_index,
+                                format!("'{}' is not an integer: {}", word, err),
+                            )
+                        })
                     })
                     .collect()
-            })
-            .collect(),
-    }
+            }
+            None => line
+                .chars()
+                .enumerate()
+                .map(|(char_index, char)| {
+                    char.to_digit(10).map(T::from).ok_or_else(|| {
+                        parse_error(
+                            line_index,
+                            char_index,
+                            format!("'{}' is not a base 10 digit", char),
+                        )
+                    })
+                })
+                .collect(),
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
     use super::{read_to_grid, read_to_vector};
-    use test::Bencher;
+    use crate::Error;
 
     static EXPECTED_RESULTS: &[[u64; 4]; 4] =
         &[[4, 6, 2, 3], [1, 5, 0, 4], [6, 3, 1, 9], [5, 0, 2, 8]];
@@ -81,15 +100,18 @@ mod tests {
     static INPUT_FILE_PATH: &str = ".test_input/file_helpers_4x4_grid.dat";
     static INPUT_FILE_PATH_DELIMITED: &str = ".test_input/file_helpers_4x4_grid_delimited.dat";
     static INPUT_FILE_DELIMITER: char = '|';
+    static INVALID_INPUT_FILE_PATH: &str = ".test_input/file_helpers_4x4_grid_invalid_digit.dat";
+    static INVALID_INPUT_FILE_PATH_DELIMITED: &str =
+        ".test_input/file_helpers_4x4_grid_invalid_delimited.dat";
 
     #[test]
     fn read_to_vector_returns_vector_of_parsed_file_contents() {
         assert_eq!(
-            read_to_vector::<u64>(INPUT_FILE_PATH, None),
+            read_to_vector::<u64>(INPUT_FILE_PATH, None).expect("input file to be valid"),
             EXPECTED_RESULTS
                 .iter()
                 .flatten()
-                .map(|num| *num)
+                .copied()
                 .collect::<Vec<u64>>()
         );
     }
@@ -97,36 +119,65 @@ mod tests {
     #[test]
     fn read_to_vector_returns_vector_of_parsed_delimited_file_contents() {
         assert_eq!(
-            read_to_vector::<u64>(INPUT_FILE_PATH_DELIMITED, Some(&INPUT_FILE_DELIMITER)),
+            read_to_vector::<u64>(INPUT_FILE_PATH_DELIMITED, Some(&INPUT_FILE_DELIMITER))
+                .expect("input file to be valid"),
             EXPECTED_RESULTS
                 .iter()
                 .flatten()
-                .map(|num| *num)
+                .copied()
                 .collect::<Vec<u64>>()
         );
     }
 
     #[test]
     fn read_to_grid_returns_2d_vector_of_parsed_file_contents() {
-        assert_eq!(read_to_grid(INPUT_FILE_PATH, None), EXPECTED_RESULTS);
+        assert_eq!(
+            read_to_grid(INPUT_FILE_PATH, None).expect("input file to be valid"),
+            EXPECTED_RESULTS
+        );
     }
 
     #[test]
     fn read_to_grid_returns_2d_vector_of_parsed_delimited_file_contents() {
         assert_eq!(
-            read_to_grid(INPUT_FILE_PATH_DELIMITED, Some(&INPUT_FILE_DELIMITER)),
+            read_to_grid(INPUT_FILE_PATH_DELIMITED, Some(&INPUT_FILE_DELIMITER))
+                .expect("input file to be valid"),
             EXPECTED_RESULTS
         );
     }
 
-    #[bench]
-    fn bench_read_to_vector_using_a_delimiter(bencher: &mut Bencher) {
-        bencher
-            .iter(|| read_to_vector::<u64>(INPUT_FILE_PATH_DELIMITED, Some(&INPUT_FILE_DELIMITER)));
+    #[test]
+    fn read_to_vector_returns_io_error_for_missing_file() {
+        assert!(matches!(
+            read_to_vector::<u64>(".test_input/missing.dat", None),
+            Err(Error::Io { .. })
+        ));
     }
 
-    #[bench]
-    fn bench_read_to_grid_using_a_delimiter(bencher: &mut Bencher) {
-        bencher.iter(|| read_to_grid(INPUT_FILE_PATH_DELIMITED, Some(&INPUT_FILE_DELIMITER)));
+    #[test]
+    fn read_to_grid_returns_parse_error_with_position_of_invalid_digit() {
+        assert!(matches!(
+            read_to_grid(INVALID_INPUT_FILE_PATH, None),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn read_to_grid_returns_parse_error_with_position_of_invalid_delimited_integer() {
+        assert!(matches!(
+            read_to_grid(
+                INVALID_INPUT_FILE_PATH_DELIMITED,
+                Some(&INPUT_FILE_DELIMITER)
+            ),
+            Err(Error::Parse {
+                line: 3,
+                column: 5,
+                ..
+            })
+        ));
     }
 }
@@ -0,0 +1,363 @@
+use crate::shared::to_english::{ONES, PLACES, TENS};
+use num::{CheckedAdd, CheckedMul, FromPrimitive, Integer};
+use std::fmt::{self, Display};
+
+/// Number spelled out in English parse error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseEnglishError {
+    /// Text has no words.
+    Empty,
+
+    /// Word is not part of a number spelled out in English.
+    UnknownWord {
+        /// Word that is not known.
+        word: String,
+    },
+
+    /// Word cannot follow the words before it.
+    UnexpectedWord {
+        /// Word that is out of place.
+        word: String,
+    },
+
+    /// Text ends before the number is complete.
+    UnexpectedEnd,
+
+    /// Number does not fit in the integer type.
+    Overflow,
+}
+
+impl Display for ParseEnglishError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(formatter, "no number words"),
+            Self::UnknownWord { word } => write!(formatter, "unknown number word '{}'", word),
+            Self::UnexpectedWord { word } => write!(formatter, "unexpected word '{}'", word),
+            Self::UnexpectedEnd => write!(formatter, "number words end unexpectedly"),
+            Self::Overflow => write!(formatter, "number does not fit in the integer type"),
+        }
+    }
+}
+
+impl std::error::Error for ParseEnglishError {}
+
+/// Conversion trait used to extend number types to allow them to be parsed from English.
+pub trait FromEnglish: Sized {
+    /// Parse number spelled out in English.
+    /// - **english:** Number spelled out in English, such as "one hundred and sixty-nine".
+    /// ## Notes
+    /// - Accepts what `ToEnglish::to_english` produces, along with upper case letters, commas,
+    ///   "and" left out, "and" after a place such as "thousand", tens and ones without a hyphen,
+    ///   and "a" in place of "one" before "hundred" or a place.
+    fn from_english(english: &str) -> Result<Self, ParseEnglishError>;
+}
+
+/// Number word, with the value it stands for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Word {
+    /// "zero".
+    Zero,
+
+    /// "one" to "nine".
+    Ones(u16),
+
+    /// "ten" to "nineteen".
+    Teens(u16),
+
+    /// "twenty" to "ninety".
+    Tens(u16),
+
+    /// "hundred".
+    Hundred,
+
+    /// "thousand" and above, as the power of 1,000 it stands for.
+    Place(u32),
+
+    /// "and".
+    And,
+
+    /// "a", standing for one.
+    Article,
+}
+
+impl Word {
+    /// Get number word of text.
+    /// - **text:** Lower case text of the word.
+    fn parse(text: &str) -> Option<Self> {
+        let position = |words: &[&str]| words.iter().position(|word| *word == text);
+
+        if let Some(digit) = position(&ONES) {
+            return Some(if digit == 0 {
+                Self::Zero
+            } else {
+                Self::Ones(digit as u16)
+            });
+        }
+
+        if let Some(index) = position(&TENS) {
+            return Some(if index < 10 {
+                Self::Teens(10 + index as u16)
+            } else {
+                Self::Tens((index as u16 - 8) * 10)
+            });
+        }
+
+        match text {
+            "hundred" => Some(Self::Hundred),
+            "and" => Some(Self::And),
+            "a" => Some(Self::Article),
+            _ => position(&PLACES[1..]).map(|index| Self::Place(index as u32 + 1)),
+        }
+    }
+}
+
+/// Part of a places group read so far.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum GroupPart {
+    /// Nothing read yet.
+    Empty,
+
+    /// "a", which must be followed by "hundred" or a place.
+    Article,
+
+    /// A single ones word, which may be followed by "hundred".
+    Ones,
+
+    /// A tens word, which may be followed by a ones word.
+    Tens,
+
+    /// "hundred", which may be followed by "and" or a number below 100.
+    Hundred,
+
+    /// "and", which must be followed by a number below 100.
+    And,
+
+    /// A complete number below 100, after any hundreds.
+    Complete,
+}
+
+impl<T: Integer + CheckedAdd + CheckedMul + FromPrimitive> FromEnglish for T {
+    fn from_english(english: &str) -> Result<Self, ParseEnglishError> {
+        let lower_case_english = english.to_lowercase();
+        let words: Vec<(&str, bool)> = lower_case_english
+            .split(|char: char| char.is_whitespace() || char == ',')
+            .filter(|text| !text.is_empty())
+            .flat_map(|text| {
+                text.split('-')
+                    .enumerate()
+                    .map(|(index, text)| (text, index > 0))
+            })
+            .collect();
+
+        if words.is_empty() {
+            return Err(ParseEnglishError::Empty);
+        }
+
+        let mut number = T::zero();
+        let mut group_value: u16 = 0;
+        let mut group_part = GroupPart::Empty;
+        let mut previous_place: Option<u32> = None;
+
+        for &(text, is_hyphenated) in &words {
+            let word = Word::parse(text).ok_or_else(|| ParseEnglishError::UnknownWord {
+                word: text.to_string(),
+            })?;
+            let unexpected_word = || ParseEnglishError::UnexpectedWord {
+                word: text.to_string(),
+            };
+
+            if is_hyphenated && !(group_part == GroupPart::Tens && matches!(word, Word::Ones(_))) {
+                return Err(unexpected_word());
+            }
+
+            group_part = match (word, group_part) {
+                (Word::Zero, _) if words.len() == 1 => GroupPart::Complete,
+                (Word::Ones(digit), GroupPart::Empty) => {
+                    group_value += digit;
+                    GroupPart::Ones
+                }
+                (Word::Ones(digit), GroupPart::Tens | GroupPart::Hundred | GroupPart::And) => {
+                    group_value += digit;
+                    GroupPart::Complete
+                }
+                (
+                    Word::Teens(value) | Word::Tens(value),
+                    GroupPart::Empty | GroupPart::Hundred | GroupPart::And,
+                ) => {
+                    group_value += value;
+                    if matches!(word, Word::Tens(_)) {
+                        GroupPart::Tens
+                    } else {
+                        GroupPart::Complete
+                    }
+                }
+                (Word::Hundred, GroupPart::Ones | GroupPart::Article) => {
+                    group_value *= 100;
+                    GroupPart::Hundred
+                }
+                (Word::And, GroupPart::Hundred) => GroupPart::And,
+                (Word::And, GroupPart::Empty) if previous_place.is_some() => GroupPart::And,
+                (Word::Article, GroupPart::Empty) => {
+                    group_value = 1;
+                    GroupPart::Article
+                }
+                (
+                    Word::Place(place),
+                    GroupPart::Ones
+                    | GroupPart::Article
+                    | GroupPart::Tens
+                    | GroupPart::Hundred
+                    | GroupPart::Complete,
+                ) if previous_place.is_none_or(|previous_place| place < previous_place) => {
+                    let place_value = (0..place).try_fold(T::one(), |place_value, _| {
+                        place_value.checked_mul(&T::from_u16(1_000)?)
+                    });
+
+                    number = place_value
+                        .zip(T::from_u16(group_value))
+                        .and_then(|(place_value, group_value)| {
+                            place_value.checked_mul(&group_value)
+                        })
+                        .and_then(|group_number| number.checked_add(&group_number))
+                        .ok_or(ParseEnglishError::Overflow)?;
+                    group_value = 0;
+                    previous_place = Some(place);
+                    GroupPart::Empty
+                }
+                _ => return Err(unexpected_word()),
+            };
+        }
+
+        if matches!(group_part, GroupPart::Article | GroupPart::And) {
+            return Err(ParseEnglishError::UnexpectedEnd);
+        }
+
+        T::from_u16(group_value)
+            .and_then(|group_value| number.checked_add(&group_value))
+            .ok_or(ParseEnglishError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromEnglish, ParseEnglishError};
+    use crate::shared::{math_helpers::primality::split_mix, ToEnglish};
+    use num::BigUint;
+
+    #[test]
+    fn from_english_parses_to_english_output() {
+        assert_eq!(u64::from_english("four hundred and twenty"), Ok(420));
+        assert_eq!(
+            u64::from_english(
+                "four hundred trillion twenty billion one hundred and sixty-nine million thirteen"
+            ),
+            Ok(400_020_169_000_013)
+        );
+        assert_eq!(u8::from_english("zero"), Ok(0));
+        assert_eq!(u32::from_english("one hundred thousand"), Ok(100_000));
+    }
+
+    #[test]
+    fn from_english_parses_common_variants() {
+        assert_eq!(u64::from_english("Four Hundred Twenty"), Ok(420));
+        assert_eq!(u64::from_english("a hundred"), Ok(100));
+        assert_eq!(u64::from_english("a thousand and one"), Ok(1_001));
+        assert_eq!(
+            u64::from_english("two million, three hundred thousand, forty five"),
+            Ok(2_300_045)
+        );
+        assert_eq!(
+            u64::from_english("a hundred and a"),
+            Err(ParseEnglishError::UnexpectedWord {
+                word: "a".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn from_english_rejects_malformed_text() {
+        let unexpected_word = |word: &str| {
+            Err(ParseEnglishError::UnexpectedWord {
+                word: word.to_string(),
+            })
+        };
+
+        assert_eq!(u64::from_english(" , "), Err(ParseEnglishError::Empty));
+        assert_eq!(
+            u64::from_english("one zillion"),
+            Err(ParseEnglishError::UnknownWord {
+                word: "zillion".to_string()
+            })
+        );
+        assert_eq!(u64::from_english("one two"), unexpected_word("two"));
+        assert_eq!(
+            u64::from_english("twenty thirty"),
+            unexpected_word("thirty")
+        );
+        assert_eq!(u64::from_english("eleven-one"), unexpected_word("one"));
+        assert_eq!(u64::from_english("one-hundred"), unexpected_word("hundred"));
+        assert_eq!(u64::from_english("hundred"), unexpected_word("hundred"));
+        assert_eq!(u64::from_english("ten hundred"), unexpected_word("hundred"));
+        assert_eq!(
+            u64::from_english("one hundred hundred"),
+            unexpected_word("hundred")
+        );
+        assert_eq!(
+            u64::from_english("one thousand million"),
+            unexpected_word("million")
+        );
+        assert_eq!(
+            u64::from_english("one thousand two thousand"),
+            unexpected_word("thousand")
+        );
+        assert_eq!(u64::from_english("zero zero"), unexpected_word("zero"));
+        assert_eq!(u64::from_english("and one"), unexpected_word("and"));
+        assert_eq!(
+            u64::from_english("one hundred and"),
+            Err(ParseEnglishError::UnexpectedEnd)
+        );
+        assert_eq!(
+            u64::from_english("a"),
+            Err(ParseEnglishError::UnexpectedEnd)
+        );
+        assert_eq!(
+            u8::from_english("three hundred"),
+            Err(ParseEnglishError::Overflow)
+        );
+        assert_eq!(
+            u64::from_english("one vigintillion"),
+            Err(ParseEnglishError::Overflow)
+        );
+    }
+
+    #[test]
+    fn from_english_round_trips_to_english() {
+        for number in 0..=2_000_u64 {
+            assert_eq!(u64::from_english(&number.to_english()), Ok(number));
+        }
+
+        let mut seed = 0x2545_F491_4F6C_DD1D;
+        for _ in 0..2_000 {
+            let number = ((u128::from(split_mix(&mut seed)) << 64)
+                | u128::from(split_mix(&mut seed)))
+                >> (split_mix(&mut seed) % 128);
+
+            assert_eq!(
+                u128::from_english(&number.to_english()),
+                Ok(number),
+                "{}",
+                number.to_english()
+            );
+        }
+
+        let vigintillion = BigUint::from(10_u8).pow(63);
+        assert_eq!(
+            BigUint::from_english("one vigintillion"),
+            Ok(vigintillion.clone())
+        );
+        assert_eq!(
+            BigUint::from_english(&(&vigintillion * 999_u16 + 1_u8).to_english()),
+            Ok(vigintillion * 999_u16 + 1_u8)
+        );
+    }
+}
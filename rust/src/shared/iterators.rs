@@ -1,4 +1,8 @@
-use crate::shared::math_helpers::is_prime_number;
+use crate::shared::{
+    combinatorics::next_permutation, math_helpers::sieve::SegmentedPrimes, LinearRecurrence,
+    LinearRecurrenceTerms,
+};
+use num::{CheckedAdd, CheckedMul, One, Zero};
 
 /// Collatz sequence iterator.
 /// ## Notes
@@ -6,6 +10,7 @@ use crate::shared::math_helpers::is_prime_number;
 ///   is reached.
 ///   - **n is even:** n / 2
 ///   - **n is odd:** 3n + 1
+/// - Panics if a term does not fit in a `u64`.
 pub struct CollatzSequence {
     /// Term returned upon iteration.
     current_term: Option<u64>,
@@ -23,94 +28,615 @@ impl Iterator for CollatzSequence {
     type Item = u64;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let current_term = match self.current_term {
-            Some(current_term) => current_term,
-            None => return None,
-        };
+        let current_term = self.current_term?;
 
         self.current_term = if current_term == 1 {
             None
         } else if current_term % 2 == 0 {
             Some(current_term / 2)
         } else {
-            Some((current_term * 3) + 1)
+            Some(
+                current_term
+                    .checked_mul(3)
+                    .and_then(|term| term.checked_add(1))
+                    .expect("Collatz term to fit in a u64, use CollatzCache for larger terms"),
+            )
         };
 
         Some(current_term)
     }
 }
 
-/// Fibonacci sequence iterator.
+/// Fibonacci sequence iterator, ending before the first term that does not fit in the integer
+/// type.
 /// ## Notes
 /// - **Fibonacci sequence:** A series of numbers where the next term is found by adding up the two
 ///   previous terms.
-pub struct FibonacciSequence {
-    /// Term returned upon iteration.
-    current_term: u64,
+pub struct FibonacciSequence<T = u64> {
+    /// Terms of the Fibonacci recurrence, past F(0).
+    terms: LinearRecurrenceTerms<T>,
+}
 
-    /// Previously returned term.
-    previous_term: u64,
+impl<T: Clone + CheckedAdd + CheckedMul + One + Zero> FibonacciSequence<T> {
+    /// Iterate through the Fibonacci sequence from its first term, 1.
+    pub fn new() -> Self {
+        let mut terms = LinearRecurrence::fibonacci().terms();
+        terms.next();
+
+        Self { terms }
+    }
 }
 
 impl Default for FibonacciSequence {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + CheckedAdd + CheckedMul + Zero> Iterator for FibonacciSequence<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.terms.next()
+    }
+}
+
+/// Prime numbers iterator.
+#[derive(Default)]
+pub struct PrimeNumbers {
+    /// Prime numbers sieved in segments.
+    primes: SegmentedPrimes,
+}
+
+impl Iterator for PrimeNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.primes.next()
+    }
+}
+
+/// Pythagorean triple, with legs in ascending order.
+/// ## Notes
+/// - **Pythagorean Triple:** Positive integers a, b and c where a² + b² = c².
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PythagoreanTriple {
+    /// Shorter leg.
+    pub a: u64,
+
+    /// Longer leg.
+    pub b: u64,
+
+    /// Hypotenuse.
+    pub c: u64,
+}
+
+impl PythagoreanTriple {
+    /// Get sum of the sides.
+    pub fn perimeter(&self) -> u64 {
+        self.a + self.b + self.c
+    }
+
+    /// Get sum of the sides, if it fits in a `u64`.
+    fn checked_perimeter(&self) -> Option<u64> {
+        self.a.checked_add(self.b)?.checked_add(self.c)
+    }
+
+    /// Get triple with each side multiplied by a multiplier, if the sides fit in a `u64`.
+    /// - **multiplier:** Number to multiply each side by.
+    fn scale(&self, multiplier: u64) -> Option<Self> {
+        Some(Self {
+            a: self.a.checked_mul(multiplier)?,
+            b: self.b.checked_mul(multiplier)?,
+            c: self.c.checked_mul(multiplier)?,
+        })
+    }
+
+    /// Get children of the triple in the Berggren tree, in the order the tree stores them, or
+    /// `None` for children whose sides do not fit in a `u64`.
+    fn berggren_children(&self) -> [Option<Self>; 3] {
+        let sides = [self.a, self.b, self.c];
+
+        BERGGREN_MATRICES.map(|matrix| {
+            let [a, b, c] = matrix.map(|row| {
+                let (added, subtracted) = row.iter().zip(sides).try_fold(
+                    (0_u64, 0_u64),
+                    |(added, subtracted), (&coefficient, side)| {
+                        let term = u64::from(coefficient.unsigned_abs()).checked_mul(side)?;
+
+                        Some(if coefficient > 0 {
+                            (added.checked_add(term)?, subtracted)
+                        } else {
+                            (added, subtracted.checked_add(term)?)
+                        })
+                    },
+                )?;
+
+                added.checked_sub(subtracted)
+            });
+
+            Some(Self {
+                a: a?,
+                b: b?,
+                c: c?,
+            })
+        })
+    }
+}
+
+/// Linear maps from a Pythagorean triple to its children in the Berggren tree, as rows of
+/// coefficients of (a, b, c).
+const BERGGREN_MATRICES: [[[i8; 3]; 3]; 3] = [
+    [[1, -2, 2], [2, -1, 2], [2, -2, 3]],
+    [[1, 2, 2], [2, 1, 2], [2, 2, 3]],
+    [[-1, 2, 2], [-2, 1, 2], [-2, 2, 3]],
+];
+
+/// Inclusive bound on the Pythagorean triples to iterate through.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TripleBound {
+    /// Maximum sum of the sides.
+    Perimeter(u64),
+
+    /// Maximum hypotenuse.
+    Hypotenuse(u64),
+}
+
+impl TripleBound {
+    /// Check if a triple is within the bound.
+    /// - **triple:** Triple to check.
+    fn contains(&self, triple: &PythagoreanTriple) -> bool {
+        match *self {
+            Self::Perimeter(maximum_perimeter) => triple
+                .checked_perimeter()
+                .is_some_and(|perimeter| perimeter <= maximum_perimeter),
+            Self::Hypotenuse(maximum_hypotenuse) => triple.c <= maximum_hypotenuse,
+        }
+    }
+}
+
+/// Pythagorean triples iterator, in no particular order.
+/// ## Notes
+/// - **Berggren Tree:** Every primitive triple, one whose sides share no common factor, is found
+///   exactly once by applying three fixed linear maps to (3, 4, 5) repeatedly. Each child has a
+///   larger perimeter and hypotenuse than its parent, so branches beyond the bound are pruned.
+/// - Branches and multiples whose sides overflow a `u64` are beyond any bound, so they are pruned
+///   too.
+pub struct PythagoreanTriples {
+    /// Bound on the triples to iterate through.
+    bound: TripleBound,
+
+    /// Whether to iterate through multiples of primitive triples.
+    include_multiples: bool,
+
+    /// Primitive triples within the bound yet to be returned, with legs in Berggren tree order.
+    pending_primitives: Vec<PythagoreanTriple>,
+
+    /// Primitive triple being multiplied and the next multiplier to return.
+    current_multiple: Option<(PythagoreanTriple, u64)>,
+}
+
+impl PythagoreanTriples {
+    /// Iterate through the primitive triples within a bound.
+    /// - **bound:** Inclusive bound on the triples.
+    pub fn primitive(bound: TripleBound) -> Self {
+        Self::new(bound, false)
+    }
+
+    /// Iterate through all triples within a bound, including multiples of primitive triples.
+    /// - **bound:** Inclusive bound on the triples.
+    pub fn with_multiples(bound: TripleBound) -> Self {
+        Self::new(bound, true)
+    }
+
+    /// Iterate through triples within a bound.
+    /// - **bound:** Inclusive bound on the triples.
+    /// - **include_multiples:** Whether to include multiples of primitive triples.
+    fn new(bound: TripleBound, include_multiples: bool) -> Self {
+        let root = PythagoreanTriple { a: 3, b: 4, c: 5 };
+
         Self {
-            current_term: 1,
-            previous_term: 0,
+            bound,
+            include_multiples,
+            pending_primitives: if bound.contains(&root) {
+                vec![root]
+            } else {
+                vec![]
+            },
+            current_multiple: None,
         }
     }
 }
 
-impl Iterator for FibonacciSequence {
-    type Item = u64;
+impl Iterator for PythagoreanTriples {
+    type Item = PythagoreanTriple;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let temporary_term = self.current_term;
-        self.current_term = self.current_term + self.previous_term;
-        self.previous_term = temporary_term;
+        if let Some((primitive, multiplier)) = self.current_multiple {
+            if let Some(triple) = primitive
+                .scale(multiplier)
+                .filter(|triple| self.bound.contains(triple))
+            {
+                self.current_multiple = Some((primitive, multiplier + 1));
+                return Some(triple);
+            }
+
+            self.current_multiple = None;
+        }
+
+        let primitive = self.pending_primitives.pop()?;
+        self.pending_primitives.extend(
+            primitive
+                .berggren_children()
+                .into_iter()
+                .flatten()
+                .filter(|child| self.bound.contains(child)),
+        );
+
+        let primitive = PythagoreanTriple {
+            a: primitive.a.min(primitive.b),
+            b: primitive.a.max(primitive.b),
+            c: primitive.c,
+        };
+        if self.include_multiples {
+            self.current_multiple = Some((primitive, 2));
+        }
 
-        Some(temporary_term)
+        Some(primitive)
     }
 }
 
-/// Prime numbers iterator.
-pub struct PrimeNumbers {
-    /// Prime number returned upon iteration.
-    current_prime: u64,
+/// Permutations iterator, in lexicographic order.
+/// ## Notes
+/// - **Narayana's Algorithm:** Each permutation is found from the one before it, see
+///   `combinatorics::next_permutation`. Repeated elements yield each distinct permutation once.
+pub struct Permutations<T> {
+    /// Permutation returned upon iteration, or `None` once every permutation is returned.
+    current_permutation: Option<Vec<T>>,
 }
 
-impl Default for PrimeNumbers {
-    fn default() -> Self {
-        Self { current_prime: 2 }
+impl<T: Clone + Ord> Permutations<T> {
+    /// Iterate through the permutations of elements, from the sorted one.
+    /// - **elements:** Elements to permute.
+    pub fn new(elements: &[T]) -> Self {
+        let mut first_permutation = elements.to_vec();
+        first_permutation.sort();
+
+        Self {
+            current_permutation: Some(first_permutation),
+        }
     }
 }
 
-impl Iterator for PrimeNumbers {
-    type Item = u64;
+impl<T: Clone + Ord> Iterator for Permutations<T> {
+    type Item = Vec<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_prime == 2 {
-            self.current_prime = 1;
+        let permutation = self.current_permutation.take()?;
+        let mut next_permutation_elements = permutation.clone();
 
-            return Some(2);
+        if next_permutation(&mut next_permutation_elements) {
+            self.current_permutation = Some(next_permutation_elements);
         }
 
-        loop {
-            self.current_prime += 2;
+        Some(permutation)
+    }
+}
 
-            if is_prime_number(self.current_prime) {
-                return Some(self.current_prime);
+/// Get elements at indices of a slice.
+/// - **elements:** Elements to select from.
+/// - **indices:** Indices of the elements to select.
+fn select<T: Clone>(elements: &[T], indices: &[usize]) -> Vec<T> {
+    indices
+        .iter()
+        .map(|&index| elements[index].clone())
+        .collect()
+}
+
+/// k-combinations iterator, in lexicographic order of the element positions.
+/// ## Notes
+/// - **k-combination:** A selection of k elements at distinct positions, regardless of order.
+pub struct Combinations<T> {
+    /// Elements to select from.
+    elements: Vec<T>,
+
+    /// Ascending positions of the combination returned upon iteration, or `None` once every
+    /// combination is returned.
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Combinations<T> {
+    /// Iterate through the combinations of k elements.
+    /// - **elements:** Elements to select from.
+    /// - **k:** Number of elements in each combination.
+    pub fn new(elements: &[T], k: usize) -> Self {
+        Self {
+            elements: elements.to_vec(),
+            indices: (k <= elements.len()).then(|| (0..k).collect()),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let combination = select(&self.elements, indices);
+        let offset = self.elements.len() - indices.len();
+
+        match (0..indices.len())
+            .rev()
+            .find(|&position| indices[position] < offset + position)
+        {
+            Some(position) => {
+                indices[position] += 1;
+                for next_position in position + 1..indices.len() {
+                    indices[next_position] = indices[next_position - 1] + 1;
+                }
             }
+            None => self.indices = None,
+        }
+
+        Some(combination)
+    }
+}
+
+/// k-combinations with replacement iterator, in lexicographic order of the element positions.
+/// ## Notes
+/// - **Combination with Replacement:** A selection of k elements where each position may be
+///   selected more than once, regardless of order.
+pub struct CombinationsWithReplacement<T> {
+    /// Elements to select from.
+    elements: Vec<T>,
+
+    /// Non-descending positions of the combination returned upon iteration, or `None` once every
+    /// combination is returned.
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> CombinationsWithReplacement<T> {
+    /// Iterate through the combinations of k elements with replacement.
+    /// - **elements:** Elements to select from.
+    /// - **k:** Number of elements in each combination.
+    pub fn new(elements: &[T], k: usize) -> Self {
+        Self {
+            elements: elements.to_vec(),
+            indices: (k == 0 || !elements.is_empty()).then(|| vec![0; k]),
         }
     }
 }
 
+impl<T: Clone> Iterator for CombinationsWithReplacement<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let combination = select(&self.elements, indices);
+        let last_index = self.elements.len().saturating_sub(1);
+
+        match indices.iter().rposition(|&index| index < last_index) {
+            Some(position) => {
+                let index = indices[position] + 1;
+                indices[position..].fill(index);
+            }
+            None => self.indices = None,
+        }
+
+        Some(combination)
+    }
+}
+
+/// Cartesian power iterator, in lexicographic order of the element positions.
+/// ## Notes
+/// - **Cartesian Power:** Every sequence of k elements, where each element may repeat.
+pub struct CartesianPower<T> {
+    /// Elements to select from.
+    elements: Vec<T>,
+
+    /// Positions of the sequence returned upon iteration, or `None` once every sequence is
+    /// returned.
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> CartesianPower<T> {
+    /// Iterate through the sequences of k elements.
+    /// - **elements:** Elements to select from.
+    /// - **k:** Number of elements in each sequence.
+    pub fn new(elements: &[T], k: usize) -> Self {
+        Self {
+            elements: elements.to_vec(),
+            indices: (k == 0 || !elements.is_empty()).then(|| vec![0; k]),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for CartesianPower<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let sequence = select(&self.elements, indices);
+        let last_index = self.elements.len().saturating_sub(1);
+
+        match indices.iter().rposition(|&index| index < last_index) {
+            Some(position) => {
+                indices[position] += 1;
+                indices[position + 1..].fill(0);
+            }
+            None => self.indices = None,
+        }
+
+        Some(sequence)
+    }
+}
+
+/// Integer partitions iterator, in reverse lexicographic order.
+/// ## Notes
+/// - **Integer Partition:** A way of writing a number as a sum of positive integers, regardless of
+///   order. Parts are returned in non-ascending order, from the number itself down to all ones.
+pub struct IntegerPartitions {
+    /// Partition returned upon iteration, or `None` once every partition is returned.
+    current_partition: Option<Vec<u64>>,
+}
+
+impl IntegerPartitions {
+    /// Iterate through the partitions of a number.
+    /// - **number:** Number to partition, where 0 has one empty partition.
+    pub fn new(number: u64) -> Self {
+        Self {
+            current_partition: Some(if number == 0 { vec![] } else { vec![number] }),
+        }
+    }
+}
+
+impl Iterator for IntegerPartitions {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let partition = self.current_partition.take()?;
+        let mut next_partition = partition.clone();
+
+        let ones = next_partition
+            .iter()
+            .rev()
+            .take_while(|&&part| part == 1)
+            .count();
+        next_partition.truncate(next_partition.len() - ones);
+
+        if let Some(last_part) = next_partition.pop() {
+            let part = last_part - 1;
+            let mut remainder = last_part + ones as u64;
+
+            while remainder >= part {
+                next_partition.push(part);
+                remainder -= part;
+            }
+            if remainder > 0 {
+                next_partition.push(remainder);
+            }
+
+            self.current_partition = Some(next_partition);
+        }
+
+        Some(partition)
+    }
+}
+
+/// Palindromes iterator, through every palindrome of a digit count in a base in ascending order.
+/// Reverse the iterator for descending order.
+/// ## Notes
+/// - **Palindrome:** A number whose digits read the same backward as forward.
+/// - Each palindrome is found by mirroring its first half, so the halves are iterated instead.
+pub struct Palindromes {
+    /// Number of digits in each palindrome.
+    digit_count: u32,
+
+    /// Base of the digits.
+    base: u64,
+
+    /// First half of the next palindrome from the front.
+    front_half: u64,
+
+    /// First half of the next palindrome from the back, exclusive.
+    back_half: u64,
+}
+
+impl Palindromes {
+    /// Iterate through the palindromes of a digit count.
+    /// - **digit_count:** Number of digits in each palindrome, where 0 is the only palindrome of
+    ///   one digit that starts with a zero.
+    /// - **base:** Base of the digits, from 2 to 256.
+    /// ## Notes
+    /// - Panics if the base raised to the digit count does not fit in a `u64`.
+    pub fn new(digit_count: u32, base: u32) -> Self {
+        assert!((2..=256).contains(&base), "base must be between 2 and 256");
+        let base = u64::from(base);
+        assert!(
+            base.checked_pow(digit_count).is_some(),
+            "palindromes must fit in a u64"
+        );
+
+        let half_digit_count = digit_count.div_ceil(2);
+        let (front_half, back_half) = match digit_count {
+            0 => (0, 0),
+            1 => (0, base),
+            _ => (base.pow(half_digit_count - 1), base.pow(half_digit_count)),
+        };
+
+        Self {
+            digit_count,
+            base,
+            front_half,
+            back_half,
+        }
+    }
+
+    /// Get palindrome from its first half.
+    /// - **half:** First half of the palindrome, including the middle digit if there is one.
+    fn mirror(&self, half: u64) -> u64 {
+        let mut remaining = if self.digit_count % 2 == 1 {
+            half / self.base
+        } else {
+            half
+        };
+        let mut palindrome = half;
+
+        for _ in 0..self.digit_count / 2 {
+            palindrome = palindrome * self.base + remaining % self.base;
+            remaining /= self.base;
+        }
+
+        palindrome
+    }
+}
+
+impl Iterator for Palindromes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front_half >= self.back_half {
+            return None;
+        }
+
+        self.front_half += 1;
+        Some(self.mirror(self.front_half - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.back_half.saturating_sub(self.front_half) as usize;
+
+        (length, Some(length))
+    }
+}
+
+impl DoubleEndedIterator for Palindromes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front_half >= self.back_half {
+            return None;
+        }
+
+        self.back_half -= 1;
+        Some(self.mirror(self.back_half))
+    }
+}
+
+impl ExactSizeIterator for Palindromes {}
+
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
-    use super::{CollatzSequence, FibonacciSequence, PrimeNumbers};
-    use test::Bencher;
+    use super::{
+        CartesianPower, CollatzSequence, Combinations, CombinationsWithReplacement,
+        FibonacciSequence, IntegerPartitions, Palindromes, Permutations, PrimeNumbers,
+        PythagoreanTriple, PythagoreanTriples, TripleBound,
+    };
+    use crate::shared::{
+        combinatorics::{binomial, nth_permutation, permutation_rank},
+        math_helpers::fibonacci::fibonacci_u128,
+        string_helpers::is_palindrome_in_base,
+        ModInt,
+    };
+    use num::BigUint;
 
     #[test]
     fn fibonacci_sequence_iterates_through_terms() {
@@ -122,6 +648,26 @@ mod tests {
         assert_eq!(actual_fibonacci_sequence, expected_fibonacci_sequence);
     }
 
+    #[test]
+    fn fibonacci_sequence_ends_before_overflowing() {
+        assert_eq!(FibonacciSequence::default().count(), 93);
+        assert_eq!(FibonacciSequence::<u128>::new().count(), 186);
+        assert_eq!(
+            FibonacciSequence::<BigUint>::new().nth(185),
+            Some(BigUint::from(
+                fibonacci_u128(186).expect("F(186) to fit in a u128")
+            ))
+        );
+        assert_eq!(
+            FibonacciSequence::<ModInt<10>>::new()
+                .take(61)
+                .map(|term| term.value())
+                .skip(59)
+                .collect::<Vec<u64>>(),
+            vec![0, 1]
+        );
+    }
+
     #[test]
     fn prime_numbers_iterates_through_primes() {
         let expected_prime_numbers: Vec<u64> = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
@@ -143,28 +689,256 @@ mod tests {
         );
     }
 
-    #[bench]
-    fn bench_fibonacci_sequence_to_fiftieth_term(bencher: &mut Bencher) {
-        bencher.iter(|| {
-            let mut fibonacci_sequence = FibonacciSequence::default();
-            fibonacci_sequence
-                .advance_by(50)
-                .expect("fibonacci sequence iterator to be infinite");
-        });
+    /// Get triples within a bound by brute force search, sorted.
+    fn brute_force_triples(bound: TripleBound, primitive_only: bool) -> Vec<PythagoreanTriple> {
+        let mut triples = vec![];
+
+        for c in 1..=500 {
+            for b in 1..c {
+                for a in 1..=b {
+                    let triple = PythagoreanTriple { a, b, c };
+
+                    if a * a + b * b == c * c
+                        && bound.contains(&triple)
+                        && (!primitive_only || num::integer::gcd(a, b) == 1)
+                    {
+                        triples.push(triple);
+                    }
+                }
+            }
+        }
+
+        triples.sort_by_key(|triple| (triple.c, triple.a));
+        triples
+    }
+
+    #[test]
+    fn pythagorean_triples_matches_brute_force_search() {
+        for (bound, primitive_only) in [
+            (TripleBound::Perimeter(500), true),
+            (TripleBound::Perimeter(500), false),
+            (TripleBound::Hypotenuse(250), true),
+            (TripleBound::Hypotenuse(250), false),
+        ] {
+            let mut triples: Vec<PythagoreanTriple> = if primitive_only {
+                PythagoreanTriples::primitive(bound).collect()
+            } else {
+                PythagoreanTriples::with_multiples(bound).collect()
+            };
+            triples.sort_by_key(|triple| (triple.c, triple.a));
+
+            assert_eq!(triples, brute_force_triples(bound, primitive_only));
+        }
+    }
+
+    #[test]
+    fn pythagorean_triples_handles_small_bounds() {
+        assert_eq!(
+            PythagoreanTriples::with_multiples(TripleBound::Perimeter(24)).collect::<Vec<_>>(),
+            vec![
+                PythagoreanTriple { a: 3, b: 4, c: 5 },
+                PythagoreanTriple { a: 6, b: 8, c: 10 },
+            ]
+        );
+        assert_eq!(
+            PythagoreanTriples::primitive(TripleBound::Hypotenuse(4)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn pythagorean_triples_prune_overflowing_triples() {
+        let root = PythagoreanTriple { a: 3, b: 4, c: 5 };
+        assert_eq!(root.scale(u64::MAX / 5 + 1), None);
+        assert!(!TripleBound::Perimeter(u64::MAX).contains(
+            &root
+                .scale(u64::MAX / 5)
+                .expect("sides of triple to fit in a u64")
+        ));
+
+        let large_triple = root
+            .scale(u64::MAX / 7)
+            .expect("sides of triple to fit in a u64");
+        assert_eq!(large_triple.berggren_children(), [None, None, None]);
+
+        for bound in [
+            TripleBound::Perimeter(u64::MAX),
+            TripleBound::Hypotenuse(u64::MAX),
+        ] {
+            for triple in PythagoreanTriples::primitive(bound).take(10_000) {
+                let [a, b, c] = [triple.a, triple.b, triple.c].map(u128::from);
+
+                assert_eq!(a * a + b * b, c * c);
+                assert!(bound.contains(&triple));
+            }
+        }
+
+        let mut multiples = PythagoreanTriples::with_multiples(TripleBound::Hypotenuse(u64::MAX));
+        multiples.current_multiple = Some((root, u64::MAX / 5 - 1));
+        assert_eq!(multiples.next(), root.scale(u64::MAX / 5 - 1));
+        assert_eq!(multiples.next(), root.scale(u64::MAX / 5));
+        assert_eq!(multiples.next(), Some(root));
     }
 
-    #[bench]
-    fn bench_prime_numbers_to_one_thousandth_prime(bencher: &mut Bencher) {
-        bencher.iter(|| {
-            let mut prime_numbers = PrimeNumbers::default();
-            prime_numbers
-                .advance_by(1_000)
-                .expect("prime numbers iterator to be infinite");
-        });
+    #[test]
+    fn permutations_iterates_in_lexicographic_order() {
+        assert_eq!(
+            Permutations::new(&['c', 'a', 'b']).collect::<Vec<Vec<char>>>(),
+            vec![
+                vec!['a', 'b', 'c'],
+                vec!['a', 'c', 'b'],
+                vec!['b', 'a', 'c'],
+                vec!['b', 'c', 'a'],
+                vec!['c', 'a', 'b'],
+                vec!['c', 'b', 'a'],
+            ]
+        );
+        assert_eq!(Permutations::new(&[1, 1, 2, 2]).count(), 6);
+        assert_eq!(Permutations::<u8>::new(&[]).count(), 1);
     }
 
-    #[bench]
-    fn bench_collatz_sequence_using_one_thousand_as_a_start_number(bencher: &mut Bencher) {
-        bencher.iter(|| CollatzSequence::new(1_000).count());
+    #[test]
+    fn permutations_match_ranks() {
+        let elements = [0, 1, 2, 3, 4, 5];
+
+        for (rank, permutation) in Permutations::new(&elements).enumerate() {
+            assert_eq!(permutation_rank(&permutation), Some(rank as u64));
+            assert_eq!(nth_permutation(&elements, rank as u64), Some(permutation));
+        }
+        assert_eq!(nth_permutation(&elements, 720), None);
+    }
+
+    #[test]
+    fn combinations_iterates_in_lexicographic_order() {
+        assert_eq!(
+            Combinations::new(&[1, 2, 3, 4], 2).collect::<Vec<Vec<u8>>>(),
+            vec![
+                vec![1, 2],
+                vec![1, 3],
+                vec![1, 4],
+                vec![2, 3],
+                vec![2, 4],
+                vec![3, 4]
+            ]
+        );
+        for k in 0..=12 {
+            assert_eq!(
+                Combinations::new(&[0; 10], k).count() as u64,
+                binomial(10, k as u64).expect("binomial to fit in a u64")
+            );
+        }
+    }
+
+    #[test]
+    fn combinations_with_replacement_iterates_in_lexicographic_order() {
+        assert_eq!(
+            CombinationsWithReplacement::new(&['a', 'b', 'c'], 2).collect::<Vec<Vec<char>>>(),
+            vec![
+                vec!['a', 'a'],
+                vec!['a', 'b'],
+                vec!['a', 'c'],
+                vec!['b', 'b'],
+                vec!['b', 'c'],
+                vec!['c', 'c']
+            ]
+        );
+        assert_eq!(
+            CombinationsWithReplacement::new(&[0; 6], 4).count() as u64,
+            binomial(9, 4).expect("binomial to fit in a u64")
+        );
+        assert_eq!(CombinationsWithReplacement::<u8>::new(&[], 0).count(), 1);
+        assert_eq!(CombinationsWithReplacement::<u8>::new(&[], 2).count(), 0);
+    }
+
+    #[test]
+    fn cartesian_power_iterates_in_lexicographic_order() {
+        assert_eq!(
+            CartesianPower::new(&[0, 1], 3).collect::<Vec<Vec<u8>>>(),
+            vec![
+                vec![0, 0, 0],
+                vec![0, 0, 1],
+                vec![0, 1, 0],
+                vec![0, 1, 1],
+                vec![1, 0, 0],
+                vec![1, 0, 1],
+                vec![1, 1, 0],
+                vec![1, 1, 1]
+            ]
+        );
+        assert_eq!(CartesianPower::new(&[0; 5], 4).count(), 625);
+        assert_eq!(CartesianPower::<u8>::new(&[], 0).count(), 1);
+        assert_eq!(CartesianPower::<u8>::new(&[], 1).count(), 0);
+    }
+
+    #[test]
+    fn integer_partitions_iterates_in_reverse_lexicographic_order() {
+        assert_eq!(
+            IntegerPartitions::new(5).collect::<Vec<Vec<u64>>>(),
+            vec![
+                vec![5],
+                vec![4, 1],
+                vec![3, 2],
+                vec![3, 1, 1],
+                vec![2, 2, 1],
+                vec![2, 1, 1, 1],
+                vec![1, 1, 1, 1, 1]
+            ]
+        );
+        assert_eq!(
+            IntegerPartitions::new(0).collect::<Vec<Vec<u64>>>(),
+            vec![vec![]]
+        );
+        assert_eq!(IntegerPartitions::new(20).count(), 627);
+        assert!(
+            IntegerPartitions::new(12).all(|partition| partition.iter().sum::<u64>() == 12
+                && partition.windows(2).all(|parts| parts[0] >= parts[1]))
+        );
+    }
+
+    #[test]
+    fn palindromes_iterates_in_either_order() {
+        assert_eq!(
+            Palindromes::new(3, 10).take(4).collect::<Vec<u64>>(),
+            vec![101, 111, 121, 131]
+        );
+        assert_eq!(
+            Palindromes::new(4, 10).rev().take(3).collect::<Vec<u64>>(),
+            vec![9_999, 9_889, 9_779]
+        );
+        assert_eq!(
+            Palindromes::new(1, 10).collect::<Vec<u64>>(),
+            (0..10).collect::<Vec<u64>>()
+        );
+        assert_eq!(Palindromes::new(0, 10).count(), 0);
+        assert_eq!(
+            Palindromes::new(4, 2).collect::<Vec<u64>>(),
+            vec![0b1001, 0b1111]
+        );
+        assert_eq!(Palindromes::new(19, 10).len(), 9_000_000_000);
+        assert_eq!(
+            Palindromes::new(19, 10).next_back(),
+            Some(9_999_999_999_999_999_999)
+        );
+    }
+
+    #[test]
+    fn palindromes_matches_palindrome_check() {
+        for base in [2_u64, 3, 10, 16] {
+            for digit_count in 1..=5 {
+                let lower_bound = if digit_count == 1 {
+                    0
+                } else {
+                    base.pow(digit_count - 1)
+                };
+                let expected_palindromes: Vec<u64> = (lower_bound..base.pow(digit_count))
+                    .filter(|&number| is_palindrome_in_base(number, base as u32))
+                    .collect();
+
+                assert_eq!(
+                    Palindromes::new(digit_count, base as u32).collect::<Vec<u64>>(),
+                    expected_palindromes
+                );
+            }
+        }
     }
 }
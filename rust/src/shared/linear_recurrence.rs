@@ -0,0 +1,524 @@
+use crate::shared::{math_helpers::modular::mod_mul, ModInt};
+use num::{CheckedAdd, CheckedMul, One, Zero};
+use std::{
+    collections::VecDeque,
+    ops::{Add, Mul},
+};
+
+/// Largest index `checked_nth` steps through the terms up to when Kitamasa's algorithm overflows.
+const STEPPED_INDEX_LIMIT: u64 = 1 << 20;
+
+/// Represents a sequence where each term is a fixed linear combination of the terms before it.
+/// ## Notes
+/// - **Linear Recurrence:** aₙ = c₁aₙ₋₁ + c₂aₙ₋₂ + ... + cₖaₙ₋ₖ, defined by the coefficients
+///   c₁ to cₖ and the initial terms a₀ to aₖ₋₁.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinearRecurrence<T> {
+    /// Coefficients c₁ to cₖ, multiplying the previous term first.
+    coefficients: Vec<T>,
+
+    /// Initial terms a₀ to aₖ₋₁.
+    initial_terms: Vec<T>,
+}
+
+impl<T: Clone> LinearRecurrence<T> {
+    /// Create a linear recurrence.
+    /// - **coefficients:** Coefficients c₁ to cₖ, multiplying the previous term first.
+    /// - **initial_terms:** Initial terms a₀ to aₖ₋₁.
+    /// ## Notes
+    /// - Panics if there are no coefficients, or not one initial term per coefficient.
+    pub fn new(coefficients: Vec<T>, initial_terms: Vec<T>) -> Self {
+        assert!(
+            !coefficients.is_empty(),
+            "linear recurrence must have coefficients"
+        );
+        assert_eq!(
+            coefficients.len(),
+            initial_terms.len(),
+            "linear recurrence must have one initial term per coefficient"
+        );
+
+        Self {
+            coefficients,
+            initial_terms,
+        }
+    }
+
+    /// Get coefficients c₁ to cₖ, multiplying the previous term first.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Get initial terms a₀ to aₖ₋₁.
+    pub fn initial_terms(&self) -> &[T] {
+        &self.initial_terms
+    }
+
+    /// Get number of previous terms each term depends on.
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Get term of an index using Kitamasa's algorithm, with the given arithmetic.
+    /// - **index:** Index n of the term.
+    /// - **zero:** Additive identity.
+    /// - **one:** Multiplicative identity.
+    /// - **add:** Adds two values, if the sum can be found.
+    /// - **mul:** Multiplies two values, if the product can be found.
+    /// ## Notes
+    /// - **Kitamasa's Algorithm:** Writes xⁿ modulo the characteristic polynomial
+    ///   xᵏ - c₁xᵏ⁻¹ - ... - cₖ as a polynomial of degree below k, whose coefficients weigh the
+    ///   initial terms into aₙ. Takes O(k² log n) operations.
+    /// - Returns `None` as soon as a sum or product cannot be found.
+    fn kitamasa(
+        &self,
+        index: u64,
+        zero: &T,
+        one: &T,
+        add: impl Fn(&T, &T) -> Option<T>,
+        mul: impl Fn(&T, &T) -> Option<T>,
+    ) -> Option<T> {
+        let order = self.order();
+        if let Some(initial_term) = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.initial_terms.get(index))
+        {
+            return Some(initial_term.clone());
+        }
+
+        let reduce = |mut polynomial: Vec<T>| {
+            for degree in (order..polynomial.len()).rev() {
+                let leading_coefficient = polynomial[degree].clone();
+
+                for (offset, coefficient) in self.coefficients.iter().enumerate() {
+                    let lower_degree = degree - 1 - offset;
+                    polynomial[lower_degree] = add(
+                        &polynomial[lower_degree],
+                        &mul(&leading_coefficient, coefficient)?,
+                    )?;
+                }
+            }
+
+            polynomial.truncate(order);
+            Some(polynomial)
+        };
+        let multiply = |left: &[T], right: &[T]| {
+            let mut product = vec![zero.clone(); 2 * order - 1];
+
+            for (left_degree, left_coefficient) in left.iter().enumerate() {
+                for (right_degree, right_coefficient) in right.iter().enumerate() {
+                    let degree = left_degree + right_degree;
+                    product[degree] =
+                        add(&product[degree], &mul(left_coefficient, right_coefficient)?)?;
+                }
+            }
+
+            reduce(product)
+        };
+
+        let mut power = index;
+        let mut base_polynomial = vec![zero.clone(); order.max(2)];
+        base_polynomial[1] = one.clone();
+        let mut base_polynomial = reduce(base_polynomial)?;
+        let mut result = vec![zero.clone(); order];
+        result[0] = one.clone();
+
+        while power > 0 {
+            if power & 1 == 1 {
+                result = multiply(&result, &base_polynomial)?;
+            }
+
+            power >>= 1;
+            if power > 0 {
+                base_polynomial = multiply(&base_polynomial, &base_polynomial)?;
+            }
+        }
+
+        result
+            .iter()
+            .zip(&self.initial_terms)
+            .try_fold(zero.clone(), |term, (weight, initial_term)| {
+                add(&term, &mul(weight, initial_term)?)
+            })
+    }
+}
+
+impl<T> LinearRecurrence<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    /// Get term of an index using Kitamasa's algorithm.
+    /// - **index:** Index n of the term.
+    /// ## Notes
+    /// - Meant for `BigUint` and `ModInt` terms. Primitive integer terms overflow quickly, so use
+    ///   `checked_nth` or `nth_mod` for them instead.
+    pub fn nth(&self, index: u64) -> T {
+        self.kitamasa(
+            index,
+            &T::zero(),
+            &T::one(),
+            |left, right| Some(left.clone() + right.clone()),
+            |left, right| Some(left.clone() * right.clone()),
+        )
+        .expect("unchecked arithmetic to always give a value")
+    }
+
+    /// Get term of an index by raising the companion matrix to a power.
+    /// - **index:** Index n of the term.
+    /// ## Notes
+    /// - **Companion Matrix:** Maps (aₙ₊ₖ₋₁, ..., aₙ) to (aₙ₊ₖ, ..., aₙ₊₁), with the coefficients
+    ///   in its first row and ones below its diagonal. Takes O(k³ log n) operations, so prefer
+    ///   `nth` for recurrences of high order.
+    /// - Like `nth`, meant for `BigUint` and `ModInt` terms.
+    pub fn nth_by_matrix(&self, index: u64) -> T {
+        let order = self.order();
+        let multiply = |left: &[Vec<T>], right: &[Vec<T>]| -> Vec<Vec<T>> {
+            (0..order)
+                .map(|row| {
+                    (0..order)
+                        .map(|column| {
+                            (0..order).fold(T::zero(), |sum, inner| {
+                                sum + left[row][inner].clone() * right[inner][column].clone()
+                            })
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+
+        let mut power = index;
+        let mut base_matrix: Vec<Vec<T>> = (0..order)
+            .map(|row| {
+                (0..order)
+                    .map(|column| match row {
+                        0 => self.coefficients[column].clone(),
+                        _ if column + 1 == row => T::one(),
+                        _ => T::zero(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut result: Vec<Vec<T>> = (0..order)
+            .map(|row| {
+                (0..order)
+                    .map(|column| if row == column { T::one() } else { T::zero() })
+                    .collect()
+            })
+            .collect();
+
+        while power > 0 {
+            if power & 1 == 1 {
+                result = multiply(&result, &base_matrix);
+            }
+
+            base_matrix = multiply(&base_matrix, &base_matrix);
+            power >>= 1;
+        }
+
+        result[order - 1]
+            .iter()
+            .zip(self.initial_terms.iter().rev())
+            .fold(T::zero(), |term, (weight, initial_term)| {
+                term + weight.clone() * initial_term.clone()
+            })
+    }
+}
+
+impl<T: One + Zero> LinearRecurrence<T> {
+    /// Create the Fibonacci recurrence, Fₙ = Fₙ₋₁ + Fₙ₋₂ with F₀ = 0 and F₁ = 1.
+    pub fn fibonacci() -> Self {
+        Self {
+            coefficients: vec![T::one(), T::one()],
+            initial_terms: vec![T::zero(), T::one()],
+        }
+    }
+}
+
+impl<T: Clone + CheckedAdd + CheckedMul + Zero + One> LinearRecurrence<T> {
+    /// Get term of an index using Kitamasa's algorithm, if it fits in the integer type.
+    /// - **index:** Index n of the term.
+    /// ## Notes
+    /// - A weight found by Kitamasa's algorithm can overflow while the term still fits, such as
+    ///   when initial terms are zero or coefficients cancel. Terms up to `STEPPED_INDEX_LIMIT` are
+    ///   then found by stepping through every term before them instead.
+    /// - Returns `None` for indices above the limit whose weights overflow, unless every initial
+    ///   term is zero.
+    pub fn checked_nth(&self, index: u64) -> Option<T> {
+        if self.initial_terms.iter().all(Zero::is_zero) {
+            return Some(T::zero());
+        }
+
+        self.kitamasa(
+            index,
+            &T::zero(),
+            &T::one(),
+            |left, right| left.checked_add(right),
+            |left, right| left.checked_mul(right),
+        )
+        .or_else(|| {
+            (index <= STEPPED_INDEX_LIMIT)
+                .then(|| self.terms().nth(index as usize))
+                .flatten()
+        })
+    }
+}
+
+impl<T: Clone + CheckedAdd + CheckedMul + Zero> LinearRecurrence<T> {
+    /// Iterate through the terms from a₀, ending before the first term that does not fit in the
+    /// integer type.
+    pub fn terms(&self) -> LinearRecurrenceTerms<T> {
+        LinearRecurrenceTerms {
+            coefficients: self.coefficients.clone(),
+            window: self.initial_terms.iter().cloned().collect(),
+            is_growing: true,
+        }
+    }
+}
+
+impl LinearRecurrence<u64> {
+    /// Get term of an index modulo a modulus using Kitamasa's algorithm.
+    /// - **index:** Index n of the term.
+    /// - **modulus:** Non-zero modulus.
+    pub fn nth_mod(&self, index: u64, modulus: u64) -> u64 {
+        let reduced = Self {
+            coefficients: self.coefficients.iter().map(|c| c % modulus).collect(),
+            initial_terms: self.initial_terms.iter().map(|a| a % modulus).collect(),
+        };
+
+        reduced
+            .kitamasa(
+                index,
+                &0,
+                &(1 % modulus),
+                |left, right| {
+                    Some(((u128::from(*left) + u128::from(*right)) % u128::from(modulus)) as u64)
+                },
+                |left, right| Some(mod_mul(*left, *right, modulus)),
+            )
+            .expect("modular arithmetic to always give a value")
+    }
+}
+
+impl<const M: u64> LinearRecurrence<ModInt<M>> {
+    /// Find the shortest linear recurrence that generates a sequence prefix.
+    /// - **prefix:** First terms of the sequence.
+    /// ## Notes
+    /// - **Berlekamp-Massey:** Grows the recurrence one term at a time, correcting it with an
+    ///   earlier recurrence whenever it predicts a term wrongly. A recurrence of order k is
+    ///   recovered from 2k terms.
+    /// - Returns `None` if the prefix is all zeros. Panics if the modulus is not prime and a
+    ///   correction needs a division it cannot make.
+    pub fn berlekamp_massey(prefix: &[ModInt<M>]) -> Option<Self> {
+        let mut connection = vec![ModInt::new(1)];
+        let mut previous_connection = vec![ModInt::new(1)];
+        let mut order = 0;
+        let mut shift = 1;
+        let mut previous_discrepancy = ModInt::new(1);
+
+        for (index, &term) in prefix.iter().enumerate() {
+            let discrepancy = (1..=order).fold(term, |discrepancy, offset| {
+                discrepancy + connection[offset] * prefix[index - offset]
+            });
+
+            if discrepancy.is_zero() {
+                shift += 1;
+                continue;
+            }
+
+            let scale = discrepancy / previous_discrepancy;
+            let corrected_connection = {
+                let mut corrected_connection = connection.clone();
+                corrected_connection.resize(
+                    corrected_connection
+                        .len()
+                        .max(previous_connection.len() + shift),
+                    ModInt::default(),
+                );
+
+                for (offset, &coefficient) in previous_connection.iter().enumerate() {
+                    corrected_connection[offset + shift] -= scale * coefficient;
+                }
+
+                corrected_connection
+            };
+
+            if 2 * order <= index {
+                previous_connection = std::mem::replace(&mut connection, corrected_connection);
+                order = index + 1 - order;
+                previous_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                connection = corrected_connection;
+                shift += 1;
+            }
+        }
+
+        connection.resize(order + 1, ModInt::default());
+
+        (order > 0).then(|| {
+            Self::new(
+                connection[1..]
+                    .iter()
+                    .map(|&coefficient| -coefficient)
+                    .collect(),
+                prefix[..order].to_vec(),
+            )
+        })
+    }
+}
+
+/// Linear recurrence terms iterator, ending before the first term that does not fit in the
+/// integer type.
+pub struct LinearRecurrenceTerms<T> {
+    /// Coefficients c₁ to cₖ, multiplying the previous term first.
+    coefficients: Vec<T>,
+
+    /// Terms yet to be returned, with the last k terms found.
+    window: VecDeque<T>,
+
+    /// Whether terms are still being found, false once a term does not fit in the integer type.
+    is_growing: bool,
+}
+
+impl<T: Clone + CheckedAdd + CheckedMul + Zero> Iterator for LinearRecurrenceTerms<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_growing {
+            let next_term = self
+                .coefficients
+                .iter()
+                .zip(self.window.iter().rev())
+                .try_fold(T::zero(), |sum, (coefficient, term)| {
+                    sum.checked_add(&coefficient.checked_mul(term)?)
+                });
+
+            match next_term {
+                Some(next_term) => self.window.push_back(next_term),
+                None => self.is_growing = false,
+            }
+        }
+
+        self.window.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearRecurrence;
+    use crate::shared::{math_helpers::fibonacci::fibonacci_big, ModInt};
+    use num::BigUint;
+
+    type Prime = ModInt<1_000_000_007>;
+
+    fn tribonacci() -> LinearRecurrence<u64> {
+        LinearRecurrence::new(vec![1, 1, 1], vec![0, 0, 1])
+    }
+
+    #[test]
+    fn nth_matches_terms() {
+        let terms: Vec<u64> = tribonacci().terms().take(60).collect();
+
+        for (index, &term) in terms.iter().enumerate() {
+            assert_eq!(tribonacci().checked_nth(index as u64), Some(term));
+            assert_eq!(tribonacci().nth_by_matrix(index as u64), term);
+            assert_eq!(tribonacci().nth_mod(index as u64, 1_000), term % 1_000);
+        }
+        assert_eq!(&terms[..10], [0, 0, 1, 1, 2, 4, 7, 13, 24, 44]);
+    }
+
+    #[test]
+    fn terms_end_before_overflowing() {
+        assert_eq!(LinearRecurrence::<u64>::fibonacci().terms().count(), 94);
+        assert_eq!(
+            LinearRecurrence::<u64>::fibonacci().terms().last(),
+            Some(12_200_160_415_121_876_738)
+        );
+    }
+
+    #[test]
+    fn checked_nth_rejects_terms_that_do_not_fit() {
+        let fibonacci = LinearRecurrence::<u64>::fibonacci();
+
+        assert_eq!(fibonacci.checked_nth(93), fibonacci.terms().last());
+        assert_eq!(fibonacci.checked_nth(94), None);
+        assert_eq!(fibonacci.checked_nth(u64::MAX), None);
+        assert_eq!(
+            LinearRecurrence::new(vec![1_u8], vec![7]).checked_nth(u64::MAX),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn checked_nth_finds_terms_whose_weights_overflow() {
+        let zeros = LinearRecurrence::new(vec![3_u64, 5], vec![0, 0]);
+        assert_eq!(zeros.checked_nth(1_000), Some(0));
+        assert_eq!(zeros.checked_nth(u64::MAX), Some(0));
+
+        let alternating = LinearRecurrence::new(vec![-1_i8, 2, 2], vec![1, -1, 1]);
+        let expected_terms: Vec<i8> = (0..500)
+            .map(|index| if index % 2 == 0 { 1 } else { -1 })
+            .collect();
+        for (index, &term) in expected_terms.iter().enumerate() {
+            assert_eq!(alternating.checked_nth(index as u64), Some(term));
+        }
+    }
+
+    #[test]
+    fn nth_works_over_big_and_modular_integers() {
+        let big_fibonacci = LinearRecurrence::<BigUint>::fibonacci();
+        let modular_fibonacci = LinearRecurrence::<Prime>::fibonacci();
+
+        for index in [0, 1, 2, 100, 1_000] {
+            assert_eq!(big_fibonacci.nth(index), fibonacci_big(index));
+            assert_eq!(
+                BigUint::from(modular_fibonacci.nth(index).value()),
+                fibonacci_big(index) % 1_000_000_007_u64
+            );
+        }
+        assert_eq!(
+            LinearRecurrence::<u64>::fibonacci().nth_mod(1_000, 1_000_000_007),
+            modular_fibonacci.nth(1_000).value()
+        );
+    }
+
+    #[test]
+    fn single_coefficient_recurrences_are_geometric() {
+        let powers_of_three = LinearRecurrence::new(vec![3_u64], vec![1]);
+
+        assert_eq!(powers_of_three.checked_nth(20), Some(3_u64.pow(20)));
+        assert_eq!(powers_of_three.checked_nth(41), None);
+        assert_eq!(powers_of_three.nth_by_matrix(20), 3_u64.pow(20));
+        assert_eq!(
+            powers_of_three.nth_mod(40, 1 << 32),
+            3_u64.pow(40) % (1 << 32)
+        );
+    }
+
+    #[test]
+    fn berlekamp_massey_recovers_recurrences() {
+        let tiling_counts = LinearRecurrence::new(
+            vec![Prime::new(4), Prime::new(1_000_000_006)],
+            vec![Prime::new(1), Prime::new(3)],
+        );
+        let prefix: Vec<Prime> = (0..10).map(|index| tiling_counts.nth(index)).collect();
+
+        assert_eq!(
+            LinearRecurrence::berlekamp_massey(&prefix),
+            Some(tiling_counts)
+        );
+
+        let tribonacci_prefix: Vec<Prime> = tribonacci().terms().take(12).map(Prime::new).collect();
+        assert_eq!(
+            LinearRecurrence::berlekamp_massey(&tribonacci_prefix),
+            Some(LinearRecurrence::new(
+                vec![Prime::new(1); 3],
+                vec![Prime::new(0), Prime::new(0), Prime::new(1)]
+            ))
+        );
+        assert_eq!(
+            LinearRecurrence::<Prime>::berlekamp_massey(&[Prime::new(0); 5]),
+            None
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use crate::shared::{factorization::prime_power_divisor_count, Factorization};
+use num::{BigUint, CheckedMul, One, Zero};
+use std::ops::Mul;
+
+/// Smallest prime factor of every number up to a limit, found with a linear sieve.
+/// ## Notes
+/// - **Linear Sieve:** Crosses out each composite number exactly once, by its smallest prime
+///   factor, so filling the tables takes O(n) time.
+struct LinearSieve {
+    /// Smallest prime factor of each number, or 0 for 0 and 1.
+    smallest_prime_factors: Vec<u64>,
+
+    /// Power of the smallest prime factor in each number.
+    smallest_prime_powers: Vec<u32>,
+
+    /// Smallest prime factor of each number raised to its power in the number.
+    smallest_prime_power_values: Vec<u64>,
+}
+
+impl LinearSieve {
+    /// Sieve the numbers up to and including a limit.
+    /// - **limit:** Inclusive upper bound of the sieved numbers.
+    fn new(limit: usize) -> Self {
+        let mut smallest_prime_factors = vec![0; limit + 1];
+        let mut smallest_prime_powers = vec![0; limit + 1];
+        let mut smallest_prime_power_values = vec![0; limit + 1];
+        let mut primes: Vec<usize> = vec![];
+
+        for number in 2..=limit {
+            if smallest_prime_factors[number] == 0 {
+                smallest_prime_factors[number] = number as u64;
+                smallest_prime_powers[number] = 1;
+                smallest_prime_power_values[number] = number as u64;
+                primes.push(number);
+            }
+
+            let smallest_prime_factor = smallest_prime_factors[number] as usize;
+            for &prime in primes.iter() {
+                if prime > smallest_prime_factor || number * prime > limit {
+                    break;
+                }
+
+                let multiple = number * prime;
+                smallest_prime_factors[multiple] = prime as u64;
+                if prime == smallest_prime_factor {
+                    smallest_prime_powers[multiple] = smallest_prime_powers[number] + 1;
+                    smallest_prime_power_values[multiple] =
+                        smallest_prime_power_values[number] * prime as u64;
+                } else {
+                    smallest_prime_powers[multiple] = 1;
+                    smallest_prime_power_values[multiple] = prime as u64;
+                }
+            }
+        }
+
+        Self {
+            smallest_prime_factors,
+            smallest_prime_powers,
+            smallest_prime_power_values,
+        }
+    }
+
+    /// Get table of a multiplicative function for every number up to the sieve limit.
+    /// - **of_prime_power:** Gets the function's value for a prime, its power and the prime
+    ///   raised to the power.
+    /// ## Notes
+    /// - **Multiplicative Function:** f(ab) = f(a)f(b) whenever a and b are coprime, so each
+    ///   value is the value of the number without its smallest prime power, times the value of
+    ///   that prime power.
+    /// - The value for 0 is zero, as the functions are only defined on positive numbers.
+    fn multiplicative_table<T>(&self, of_prime_power: impl Fn(u64, u32, u64) -> T) -> Vec<T>
+    where
+        T: Copy + Mul<Output = T> + One + Zero,
+    {
+        let mut table = vec![T::zero(); self.smallest_prime_factors.len()];
+
+        for number in 1..table.len() {
+            table[number] = if number == 1 {
+                T::one()
+            } else {
+                let prime_power_value = self.smallest_prime_power_values[number];
+
+                table[number / prime_power_value as usize]
+                    * of_prime_power(
+                        self.smallest_prime_factors[number],
+                        self.smallest_prime_powers[number],
+                        prime_power_value,
+                    )
+            };
+        }
+
+        table
+    }
+
+    /// Get table of a multiplicative function for every number up to the sieve limit, if every
+    /// value fits in the integer type.
+    /// - **of_prime_power:** Gets the function's value for a prime, its power and the prime
+    ///   raised to the power, if it fits in the integer type.
+    fn checked_multiplicative_table<T>(
+        &self,
+        of_prime_power: impl Fn(u64, u32, u64) -> Option<T>,
+    ) -> Option<Vec<T>>
+    where
+        T: Copy + CheckedMul + One + Zero,
+    {
+        let mut table = vec![T::zero(); self.smallest_prime_factors.len()];
+
+        for number in 1..table.len() {
+            table[number] = if number == 1 {
+                T::one()
+            } else {
+                let prime_power_value = self.smallest_prime_power_values[number];
+
+                table[number / prime_power_value as usize].checked_mul(&of_prime_power(
+                    self.smallest_prime_factors[number],
+                    self.smallest_prime_powers[number],
+                    prime_power_value,
+                )?)?
+            };
+        }
+
+        Some(table)
+    }
+}
+
+/// Get value of a multiplicative function for input number, from its prime factorization.
+/// - **input_number:** Positive input number to get the function's value for.
+/// - **of_prime_power:** Gets the function's value for a prime, its power and the prime raised to
+///   the power.
+fn multiplicative<T>(input_number: u64, of_prime_power: impl Fn(u64, u32, u64) -> T) -> T
+where
+    T: Mul<Output = T> + One,
+{
+    Factorization::new(input_number)
+        .prime_powers()
+        .iter()
+        .map(|prime_power| {
+            let power = prime_power.power as u32;
+
+            of_prime_power(
+                prime_power.base_value,
+                power,
+                prime_power.base_value.pow(power),
+            )
+        })
+        .fold(T::one(), |product, value| product * value)
+}
+
+/// Get value of a multiplicative function for input number, from its prime factorization, if it
+/// fits in the integer type.
+/// - **input_number:** Positive input number to get the function's value for.
+/// - **of_prime_power:** Gets the function's value for a prime, its power and the prime raised to
+///   the power, if it fits in the integer type.
+fn checked_multiplicative<T>(
+    input_number: u64,
+    of_prime_power: impl Fn(u64, u32, u64) -> Option<T>,
+) -> Option<T>
+where
+    T: CheckedMul + One,
+{
+    Factorization::new(input_number)
+        .prime_powers()
+        .iter()
+        .try_fold(T::one(), |product, prime_power| {
+            let power = prime_power.power as u32;
+
+            product.checked_mul(&of_prime_power(
+                prime_power.base_value,
+                power,
+                prime_power.base_value.pow(power),
+            )?)
+        })
+}
+
+/// Get sum of the kth powers of the divisors of a prime power, if it fits in a `u128`.
+fn divisor_sum_of_prime_power(prime: u64, power: u32, divisor_power: u32) -> Option<u128> {
+    let prime_to_divisor_power = u128::from(prime).checked_pow(divisor_power)?;
+
+    (0..power)
+        .try_fold((1_u128, 1_u128), |(sum, term), _| {
+            let term = term.checked_mul(prime_to_divisor_power)?;
+            Some((sum.checked_add(term)?, term))
+        })
+        .map(|(sum, _)| sum)
+}
+
+/// Get Euler's totient of a prime power.
+fn euler_totient_of_prime_power(prime: u64, _power: u32, prime_power_value: u64) -> u64 {
+    prime_power_value - prime_power_value / prime
+}
+
+/// Get Möbius function of a prime power.
+fn mobius_of_prime_power(_prime: u64, power: u32, _prime_power_value: u64) -> i8 {
+    if power == 1 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Get Liouville function of a prime power.
+fn liouville_of_prime_power(_prime: u64, power: u32, _prime_power_value: u64) -> i8 {
+    if power % 2 == 1 {
+        -1
+    } else {
+        1
+    }
+}
+
+/// Get radical of a prime power.
+fn radical_of_prime_power(prime: u64, _power: u32, _prime_power_value: u64) -> u64 {
+    prime
+}
+
+/// Get number of divisors of input number.
+/// - **input_number:** Positive input number to count divisors of.
+/// ## Notes
+/// - **Divisor Count:** τ(n), the number of positive numbers that divide evenly into n, see
+///   `Factorization::divisor_count`.
+pub fn divisor_count(input_number: u64) -> u64 {
+    Factorization::new(input_number).divisor_count()
+}
+
+/// Get sum of the kth powers of the divisors of input number.
+/// - **input_number:** Positive input number to sum divisors of.
+/// - **divisor_power:** Power to raise each divisor to before summing, k.
+/// ## Notes
+/// - **Divisor Function:** σₖ(n), where σ₀ is the divisor count and σ₁ the divisor sum.
+/// - Panics if the sum does not fit in a `u128`, which never happens for k = 1. Use
+///   `checked_divisor_sum` or `divisor_sum_big` for larger k.
+pub fn divisor_sum(input_number: u64, divisor_power: u32) -> u128 {
+    checked_divisor_sum(input_number, divisor_power).expect("divisor sum to fit in a u128")
+}
+
+/// Get sum of the kth powers of the divisors of input number, if it fits in a `u128`.
+/// - **input_number:** Positive input number to sum divisors of.
+/// - **divisor_power:** Power to raise each divisor to before summing, k.
+pub fn checked_divisor_sum(input_number: u64, divisor_power: u32) -> Option<u128> {
+    checked_multiplicative(input_number, |prime, power, _| {
+        divisor_sum_of_prime_power(prime, power, divisor_power)
+    })
+}
+
+/// Get sum of the kth powers of the divisors of input number as an arbitrarily large integer.
+/// - **input_number:** Positive input number to sum divisors of.
+/// - **divisor_power:** Power to raise each divisor to before summing, k.
+/// ## Notes
+/// - See `Factorization::divisor_power_sum`.
+pub fn divisor_sum_big(input_number: u64, divisor_power: u32) -> BigUint {
+    Factorization::new(input_number).divisor_power_sum(divisor_power)
+}
+
+/// Get number of positive numbers up to input number that are coprime to it.
+/// - **input_number:** Positive input number to get Euler's totient for.
+/// ## Notes
+/// - **Euler's Totient:** φ(n), the count of k ≤ n with gcd(n, k) = 1.
+pub fn euler_totient(input_number: u64) -> u64 {
+    multiplicative(input_number, euler_totient_of_prime_power)
+}
+
+/// Get Möbius function of input number.
+/// - **input_number:** Positive input number to get Möbius function for.
+/// ## Notes
+/// - **Möbius Function:** μ(n), 0 if n has a squared prime factor, otherwise -1 raised to the
+///   number of prime factors of n.
+pub fn mobius(input_number: u64) -> i8 {
+    multiplicative(input_number, mobius_of_prime_power)
+}
+
+/// Get Liouville function of input number.
+/// - **input_number:** Positive input number to get Liouville function for.
+/// ## Notes
+/// - **Liouville Function:** λ(n), -1 raised to the number of prime factors of n, counted with
+///   multiplicity.
+pub fn liouville(input_number: u64) -> i8 {
+    multiplicative(input_number, liouville_of_prime_power)
+}
+
+/// Get radical of input number.
+/// - **input_number:** Positive input number to get radical for.
+/// ## Notes
+/// - **Radical:** rad(n), the product of the distinct prime factors of n.
+pub fn radical(input_number: u64) -> u64 {
+    multiplicative(input_number, radical_of_prime_power)
+}
+
+/// Get divisor count of every number up to a limit, indexed by number.
+/// - **limit:** Inclusive upper bound of the numbers.
+pub fn divisor_count_table(limit: usize) -> Vec<u64> {
+    LinearSieve::new(limit)
+        .checked_multiplicative_table(|_, power, _| prime_power_divisor_count(u64::from(power)))
+        .expect("divisor counts of numbers up to a usize to fit in a u64")
+}
+
+/// Get sum of the kth powers of the divisors of every number up to a limit, indexed by number.
+/// - **limit:** Inclusive upper bound of the numbers.
+/// - **divisor_power:** Power to raise each divisor to before summing, k.
+/// ## Notes
+/// - Panics if a sum does not fit in a `u128`, which never happens for k = 1.
+pub fn divisor_sum_table(limit: usize, divisor_power: u32) -> Vec<u128> {
+    checked_divisor_sum_table(limit, divisor_power).expect("divisor sums to fit in a u128")
+}
+
+/// Get sum of the kth powers of the divisors of every number up to a limit, indexed by number, if
+/// every sum fits in a `u128`.
+/// - **limit:** Inclusive upper bound of the numbers.
+/// - **divisor_power:** Power to raise each divisor to before summing, k.
+pub fn checked_divisor_sum_table(limit: usize, divisor_power: u32) -> Option<Vec<u128>> {
+    LinearSieve::new(limit).checked_multiplicative_table(|prime, power, _| {
+        divisor_sum_of_prime_power(prime, power, divisor_power)
+    })
+}
+
+/// Get Euler's totient of every number up to a limit, indexed by number.
+/// - **limit:** Inclusive upper bound of the numbers.
+pub fn euler_totient_table(limit: usize) -> Vec<u64> {
+    LinearSieve::new(limit).multiplicative_table(euler_totient_of_prime_power)
+}
+
+/// Get Möbius function of every number up to a limit, indexed by number.
+/// - **limit:** Inclusive upper bound of the numbers.
+pub fn mobius_table(limit: usize) -> Vec<i8> {
+    LinearSieve::new(limit).multiplicative_table(mobius_of_prime_power)
+}
+
+/// Get Liouville function of every number up to a limit, indexed by number.
+/// - **limit:** Inclusive upper bound of the numbers.
+pub fn liouville_table(limit: usize) -> Vec<i8> {
+    LinearSieve::new(limit).multiplicative_table(liouville_of_prime_power)
+}
+
+/// Get radical of every number up to a limit, indexed by number.
+/// - **limit:** Inclusive upper bound of the numbers.
+pub fn radical_table(limit: usize) -> Vec<u64> {
+    LinearSieve::new(limit).multiplicative_table(radical_of_prime_power)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        checked_divisor_sum, checked_divisor_sum_table, divisor_count, divisor_count_table,
+        divisor_sum, divisor_sum_big, divisor_sum_table, euler_totient, euler_totient_table,
+        liouville, liouville_table, mobius, mobius_table, radical, radical_table,
+    };
+    use num::{BigUint, Integer};
+
+    /// Limit of the numbers the tables are checked up to.
+    const LIMIT: usize = 2_000;
+
+    /// Get divisors of input number by trial division.
+    fn divisors(input_number: u64) -> Vec<u64> {
+        (1..=input_number)
+            .filter(|divisor| input_number.is_multiple_of(*divisor))
+            .collect()
+    }
+
+    #[test]
+    fn divisor_functions_match_divisors() {
+        for input_number in 1..=LIMIT as u64 {
+            let divisors = divisors(input_number);
+
+            assert_eq!(divisor_count(input_number), divisors.len() as u64);
+            assert_eq!(divisor_sum(input_number, 0), divisors.len() as u128);
+            assert_eq!(
+                divisor_sum(input_number, 1),
+                divisors.iter().map(|&divisor| u128::from(divisor)).sum()
+            );
+            assert_eq!(
+                divisor_sum(input_number, 2),
+                divisors
+                    .iter()
+                    .map(|&divisor| u128::from(divisor).pow(2))
+                    .sum()
+            );
+            assert_eq!(
+                divisor_sum_big(input_number, 3),
+                divisors
+                    .iter()
+                    .map(|&divisor| BigUint::from(divisor).pow(3))
+                    .sum()
+            );
+        }
+    }
+
+    #[test]
+    fn checked_divisor_sums_reject_sums_that_do_not_fit() {
+        let mersenne_prime = (1 << 61) - 1;
+
+        assert_eq!(
+            checked_divisor_sum(mersenne_prime, 2),
+            Some(u128::from(mersenne_prime).pow(2) + 1)
+        );
+        assert_eq!(checked_divisor_sum(mersenne_prime, 3), None);
+        assert_eq!(
+            divisor_sum_big(mersenne_prime, 3),
+            BigUint::from(mersenne_prime).pow(3) + 1_u8
+        );
+        assert_eq!(
+            checked_divisor_sum(u64::MAX, 1),
+            Some(divisor_sum(u64::MAX, 1))
+        );
+        assert_eq!(checked_divisor_sum_table(100, 20), None);
+        assert_eq!(
+            checked_divisor_sum_table(100, 2),
+            Some(divisor_sum_table(100, 2))
+        );
+    }
+
+    #[test]
+    fn euler_totient_counts_coprime_numbers() {
+        for input_number in 1..=LIMIT as u64 {
+            assert_eq!(
+                euler_totient(input_number),
+                (1..=input_number)
+                    .filter(|k| input_number.gcd(k) == 1)
+                    .count() as u64
+            );
+        }
+    }
+
+    #[test]
+    fn sign_functions_and_radical_match_known_values() {
+        assert_eq!(
+            (1..=10).map(mobius).collect::<Vec<i8>>(),
+            vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1]
+        );
+        assert_eq!(
+            (1..=10).map(liouville).collect::<Vec<i8>>(),
+            vec![1, -1, -1, 1, -1, 1, -1, -1, 1, 1]
+        );
+        assert_eq!(
+            (1..=10).map(radical).collect::<Vec<u64>>(),
+            vec![1, 2, 3, 2, 5, 6, 7, 2, 3, 10]
+        );
+        assert_eq!(radical(u64::MAX), u64::MAX);
+        assert_eq!(
+            euler_totient(4_294_967_291 * 4_294_967_279),
+            4_294_967_290 * 4_294_967_278
+        );
+    }
+
+    #[test]
+    fn tables_match_single_values() {
+        let divisor_counts = divisor_count_table(LIMIT);
+        let divisor_sums = divisor_sum_table(LIMIT, 1);
+        let euler_totients = euler_totient_table(LIMIT);
+        let mobius_values = mobius_table(LIMIT);
+        let liouville_values = liouville_table(LIMIT);
+        let radicals = radical_table(LIMIT);
+
+        assert_eq!(divisor_counts.len(), LIMIT + 1);
+        assert_eq!(divisor_counts[0], 0);
+        for number in 1..=LIMIT {
+            let input_number = number as u64;
+
+            assert_eq!(divisor_counts[number], divisor_count(input_number));
+            assert_eq!(divisor_sums[number], divisor_sum(input_number, 1));
+            assert_eq!(euler_totients[number], euler_totient(input_number));
+            assert_eq!(mobius_values[number], mobius(input_number));
+            assert_eq!(liouville_values[number], liouville(input_number));
+            assert_eq!(radicals[number], radical(input_number));
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use crate::shared::{
+    math_helpers::{
+        modular::{mod_add_u128, mod_mul_u128},
+        primality::{is_prime_u128, split_mix},
+    },
+    Exponent,
+};
+use num::Integer;
+
+/// Largest divisor tried by trial division before falling back to Pollard-Brent rho.
+const TRIAL_DIVISION_LIMIT: u128 = 1_000;
+
+/// Number of sequence steps between greatest common divisor checks in Pollard-Brent rho.
+const GCD_BATCH_LENGTH: u64 = 128;
+
+/// Get prime factors of a 128-bit input number, in ascending order of base value.
+/// - **input_number:** Input number to get prime factors for.
+/// ## Notes
+/// - **Prime Factorization:** Small prime factors are removed by trial division, then the rest is
+///   split by Pollard-Brent rho until every factor passes Miller-Rabin.
+/// - **Running Time:** Pollard-Brent rho takes about p^(1/2) steps to find a prime factor p, so
+///   inputs with at most one prime factor above 2^40 factor in milliseconds.
+pub fn prime_factors_u128(mut input_number: u128) -> Vec<Exponent<u128>> {
+    let mut prime_factors: Vec<Exponent<u128>> = vec![];
+
+    if input_number == 0 {
+        return prime_factors;
+    }
+
+    let mut divisor = 2;
+    while divisor <= TRIAL_DIVISION_LIMIT && divisor * divisor <= input_number {
+        let mut power = 0;
+        while input_number.is_multiple_of(divisor) {
+            input_number /= divisor;
+            power += 1;
+        }
+
+        if power > 0 {
+            prime_factors.push(Exponent {
+                base_value: divisor,
+                power,
+            });
+        }
+
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+
+    let mut seed = input_number as u64;
+    let mut unsplit_factors = vec![input_number];
+    while let Some(factor) = unsplit_factors.pop() {
+        if factor == 1 {
+            continue;
+        } else if is_prime_u128(factor) {
+            match prime_factors
+                .iter_mut()
+                .find(|prime_factor| prime_factor.base_value == factor)
+            {
+                Some(prime_factor) => prime_factor.power += 1,
+                None => prime_factors.push(Exponent {
+                    base_value: factor,
+                    power: 1,
+                }),
+            }
+        } else {
+            let divisor = pollard_brent(factor, &mut seed);
+            unsplit_factors.extend([divisor, factor / divisor]);
+        }
+    }
+
+    prime_factors.sort_by_key(|prime_factor| prime_factor.base_value);
+
+    prime_factors
+}
+
+/// Find a non-trivial divisor of a composite input number using Pollard-Brent rho.
+/// - **input_number:** Odd composite input number to find a divisor of.
+/// - **seed:** Pseudo-random number generator state, for the sequence constants.
+/// ## Notes
+/// - **Pollard's Rho:** The sequence x ↦ x² + c modulo a prime factor p eventually cycles, after
+///   about p^(1/2) steps, at which point a greatest common divisor with the input reveals p.
+/// - **Brent's Variant:** Finds the cycle by doubling the distance between compared terms, and
+///   batches greatest common divisors by multiplying differences together.
+fn pollard_brent(input_number: u128, seed: &mut u64) -> u128 {
+    if input_number.is_multiple_of(2) {
+        return 2;
+    }
+
+    let random_below = |seed: &mut u64, bound: u128| {
+        ((u128::from(split_mix(seed)) << 64) | u128::from(split_mix(seed))) % bound
+    };
+
+    loop {
+        let increment = random_below(seed, input_number - 1) + 1;
+        let step = |term: u128| {
+            mod_add_u128(
+                mod_mul_u128(term, term, input_number),
+                increment,
+                input_number,
+            )
+        };
+
+        let mut term = random_below(seed, input_number);
+        let mut saved_term = term;
+        let mut batch_start_term = term;
+        let mut product = 1;
+        let mut divisor = 1;
+        let mut cycle_length = 1;
+
+        while divisor == 1 {
+            saved_term = term;
+            for _ in 0..cycle_length {
+                term = step(term);
+            }
+
+            let mut steps = 0;
+            while steps < cycle_length && divisor == 1 {
+                batch_start_term = term;
+                for _ in 0..GCD_BATCH_LENGTH.min(cycle_length - steps) {
+                    term = step(term);
+                    product = mod_mul_u128(product, saved_term.abs_diff(term), input_number);
+                }
+
+                divisor = product.gcd(&input_number);
+                steps += GCD_BATCH_LENGTH;
+            }
+
+            cycle_length *= 2;
+        }
+
+        if divisor == input_number {
+            loop {
+                batch_start_term = step(batch_start_term);
+                divisor = saved_term.abs_diff(batch_start_term).gcd(&input_number);
+
+                if divisor > 1 {
+                    break;
+                }
+            }
+        }
+
+        if divisor != input_number {
+            return divisor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prime_factors_u128;
+    use crate::shared::{
+        math_helpers::{
+            is_prime_number,
+            primality::{is_prime_u128, split_mix},
+            prime_factors,
+        },
+        Exponent,
+    };
+
+    /// Get prime factors of input number with the trial division routine `prime_factors` used
+    /// before Pollard-Brent rho, kept as is to cross-check against.
+    /// ## Notes
+    /// - Its `f32` square root bound is only exact for inputs up to 2^24.
+    fn baseline_prime_factors(mut input_number: u64) -> Vec<Exponent> {
+        let mut prime_factors = vec![];
+
+        if input_number == 0 {
+            return prime_factors;
+        }
+
+        fn add_prime_factor(prime_factors: &mut Vec<Exponent>, base_value: u64) {
+            match prime_factors
+                .iter_mut()
+                .find(|factor| factor.base_value == base_value)
+            {
+                Some(factor) => factor.power += 1,
+                None => prime_factors.push(Exponent {
+                    base_value,
+                    power: 1,
+                }),
+            }
+        }
+
+        while input_number.is_multiple_of(2) {
+            add_prime_factor(&mut prime_factors, 2);
+            input_number /= 2;
+        }
+
+        let mut i = 3;
+        loop {
+            while input_number.is_multiple_of(i) {
+                add_prime_factor(&mut prime_factors, i);
+                input_number /= i;
+            }
+
+            if i as f32 > (input_number as f32).sqrt() {
+                break;
+            }
+
+            i += 2;
+        }
+
+        if input_number > 2 {
+            add_prime_factor(&mut prime_factors, input_number);
+        }
+
+        prime_factors
+    }
+
+    #[test]
+    fn prime_factors_matches_baseline_on_random_inputs() {
+        let mut seed = 13;
+
+        for input_number in 0..2_000 {
+            assert_eq!(
+                prime_factors(input_number),
+                baseline_prime_factors(input_number),
+                "{}",
+                input_number
+            );
+        }
+
+        for bits in [16, 20, 24] {
+            for _ in 0..200 {
+                let input_number = split_mix(&mut seed) >> (64 - bits);
+
+                assert_eq!(
+                    prime_factors(input_number),
+                    baseline_prime_factors(input_number),
+                    "{}",
+                    input_number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn prime_factors_multiplies_back_to_random_u64_inputs() {
+        let mut seed = 64;
+
+        for _ in 0..500 {
+            let input_number = split_mix(&mut seed) >> (split_mix(&mut seed) % 64);
+            let prime_factors = prime_factors(input_number);
+
+            assert!(prime_factors
+                .windows(2)
+                .all(|pair| pair[0].base_value < pair[1].base_value));
+            assert!(prime_factors
+                .iter()
+                .all(|prime_factor| is_prime_number(prime_factor.base_value)));
+            assert_eq!(
+                prime_factors
+                    .iter()
+                    .map(|prime_factor| u128::from(prime_factor.base_value)
+                        .pow(prime_factor.power as u32))
+                    .product::<u128>(),
+                u128::from(input_number.max(1)),
+                "{}",
+                input_number
+            );
+        }
+    }
+
+    #[test]
+    fn prime_factors_splits_semiprimes_with_large_factors() {
+        assert_eq!(
+            prime_factors(4_294_967_291 * 4_294_967_279),
+            vec![
+                Exponent {
+                    base_value: 4_294_967_279,
+                    power: 1,
+                },
+                Exponent {
+                    base_value: 4_294_967_291,
+                    power: 1,
+                },
+            ]
+        );
+        assert_eq!(
+            prime_factors(u64::MAX),
+            Vec::from(
+                [3, 5, 17, 257, 641, 65_537, 6_700_417].map(|base_value| Exponent {
+                    base_value,
+                    power: 1,
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn prime_factors_u128_multiplies_back_to_random_inputs() {
+        let mut seed = 128;
+
+        for _ in 0..100 {
+            let input_number =
+                u128::from(split_mix(&mut seed)) * u128::from(split_mix(&mut seed) >> 40);
+            let prime_factors = prime_factors_u128(input_number);
+
+            assert!(prime_factors
+                .windows(2)
+                .all(|pair| pair[0].base_value < pair[1].base_value));
+            assert!(prime_factors
+                .iter()
+                .all(|prime_factor| is_prime_u128(prime_factor.base_value)));
+            assert_eq!(
+                prime_factors
+                    .iter()
+                    .map(|prime_factor| prime_factor.base_value.pow(prime_factor.power as u32))
+                    .product::<u128>(),
+                input_number
+            );
+        }
+    }
+
+    #[test]
+    fn prime_factors_u128_handles_edge_cases() {
+        assert!(prime_factors_u128(0).is_empty());
+        assert!(prime_factors_u128(1).is_empty());
+        assert_eq!(
+            prime_factors_u128(1 << 127),
+            vec![Exponent {
+                base_value: 2,
+                power: 127,
+            }]
+        );
+        assert_eq!(
+            prime_factors_u128((1 << 127) - 1),
+            vec![Exponent {
+                base_value: (1 << 127) - 1,
+                power: 1,
+            }]
+        );
+    }
+}
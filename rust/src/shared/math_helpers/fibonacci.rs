@@ -0,0 +1,189 @@
+use crate::shared::math_helpers::modular::mod_mul;
+use num::{BigUint, CheckedAdd, CheckedMul, CheckedSub, One, Zero};
+
+/// Get a pair of consecutive Fibonacci numbers, F(n) and F(n + 1), using fast doubling.
+/// - **index:** Index n of the first Fibonacci number.
+/// ## Notes
+/// - **Fast Doubling:** F(2k) = F(k)(2F(k + 1) - F(k)) and F(2k + 1) = F(k)² + F(k + 1)², so each
+///   bit of n costs a few multiplications.
+/// - Returns `None` if a number does not fit in the integer type.
+fn fibonacci_pair<T>(index: u64) -> Option<(T, T)>
+where
+    T: Clone + CheckedAdd + CheckedMul + CheckedSub + One + Zero,
+{
+    let mut pair = (T::zero(), T::one());
+
+    for bit in (0..(u64::BITS - index.leading_zeros())).rev() {
+        let (current, next) = pair;
+        let doubled_next = next.checked_add(&next)?;
+        let even = current.checked_mul(&doubled_next.checked_sub(&current)?)?;
+        let odd = current
+            .checked_mul(&current)?
+            .checked_add(&next.checked_mul(&next)?)?;
+
+        pair = if (index >> bit) & 1 == 1 {
+            let following = even.checked_add(&odd)?;
+            (odd, following)
+        } else {
+            (even, odd)
+        };
+    }
+
+    Some(pair)
+}
+
+/// Get Fibonacci number of an index in an integer type, if it fits.
+/// - **index:** Index n of the Fibonacci number, where F(0) = 0 and F(1) = 1.
+fn checked_fibonacci<T>(index: u64) -> Option<T>
+where
+    T: Clone + CheckedAdd + CheckedMul + CheckedSub + One + Zero,
+{
+    match index.checked_sub(1) {
+        Some(previous_index) => fibonacci_pair::<T>(previous_index).map(|(_, current)| current),
+        None => Some(T::zero()),
+    }
+}
+
+/// Get Fibonacci number of an index, if it fits in a `u64`.
+/// - **index:** Index n of the Fibonacci number, where F(0) = 0 and F(1) = 1.
+/// ## Notes
+/// - F(93) is the largest Fibonacci number that fits in a `u64`.
+pub fn fibonacci(index: u64) -> Option<u64> {
+    checked_fibonacci(index)
+}
+
+/// Get Fibonacci number of an index, if it fits in a `u128`.
+/// - **index:** Index n of the Fibonacci number, where F(0) = 0 and F(1) = 1.
+/// ## Notes
+/// - F(186) is the largest Fibonacci number that fits in a `u128`.
+pub fn fibonacci_u128(index: u64) -> Option<u128> {
+    checked_fibonacci(index)
+}
+
+/// Get Fibonacci number of an index as an arbitrarily large integer.
+/// - **index:** Index n of the Fibonacci number, where F(0) = 0 and F(1) = 1.
+pub fn fibonacci_big(index: u64) -> BigUint {
+    checked_fibonacci(index).expect("arbitrarily large integers to not overflow")
+}
+
+/// Get Fibonacci number of an index modulo a modulus, using matrix exponentiation.
+/// - **index:** Index n of the Fibonacci number, where F(0) = 0 and F(1) = 1.
+/// - **modulus:** Non-zero modulus.
+/// ## Notes
+/// - **Matrix Power:** [[1, 1], [1, 0]]^n = [[F(n + 1), F(n)], [F(n), F(n - 1)]], raised to the
+///   power by squaring. The matrix is symmetric, so three of its entries are tracked.
+pub fn fibonacci_mod(index: u64, modulus: u64) -> u64 {
+    let multiply = |[a, b, d]: [u64; 3], [e, f, h]: [u64; 3]| {
+        let add = |left: u64, right: u64| {
+            ((u128::from(left) + u128::from(right)) % u128::from(modulus)) as u64
+        };
+
+        [
+            add(mod_mul(a, e, modulus), mod_mul(b, f, modulus)),
+            add(mod_mul(a, f, modulus), mod_mul(b, h, modulus)),
+            add(mod_mul(b, f, modulus), mod_mul(d, h, modulus)),
+        ]
+    };
+
+    let mut power = index;
+    let mut base_matrix = [1 % modulus, 1 % modulus, 0];
+    let mut result = [1 % modulus, 0, 1 % modulus];
+
+    while power > 0 {
+        if power & 1 == 1 {
+            result = multiply(result, base_matrix);
+        }
+
+        base_matrix = multiply(base_matrix, base_matrix);
+        power >>= 1;
+    }
+
+    result[1]
+}
+
+/// Get period of the Fibonacci numbers modulo a modulus.
+/// - **modulus:** Non-zero modulus.
+/// ## Notes
+/// - **Pisano Period:** π(m), the length of the cycle F(n) mod m repeats with. Found by stepping
+///   through the sequence until 0, 1 comes around again, which takes at most 6m steps.
+pub fn pisano_period(modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 1;
+    }
+
+    let (mut current, mut next) = (0, 1);
+    let mut period = 0;
+
+    loop {
+        (current, next) = (
+            next,
+            ((u128::from(current) + u128::from(next)) % u128::from(modulus)) as u64,
+        );
+        period += 1;
+
+        if current == 0 && next == 1 {
+            return period;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fibonacci, fibonacci_big, fibonacci_mod, fibonacci_u128, pisano_period};
+    use num::BigUint;
+
+    #[test]
+    fn fibonacci_returns_fibonacci_numbers() {
+        assert_eq!(
+            (0..12).map(fibonacci).collect::<Vec<Option<u64>>>(),
+            [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89].map(Some)
+        );
+        assert_eq!(fibonacci(93), Some(12_200_160_415_121_876_738));
+        assert_eq!(fibonacci(94), None);
+    }
+
+    #[test]
+    fn fibonacci_u128_returns_fibonacci_numbers() {
+        assert_eq!(fibonacci_u128(93), Some(12_200_160_415_121_876_738));
+        assert_eq!(
+            fibonacci_u128(186),
+            Some(332_825_110_087_067_562_321_196_029_789_634_457_848)
+        );
+        assert_eq!(fibonacci_u128(187), None);
+    }
+
+    #[test]
+    fn fibonacci_big_matches_u128() {
+        for index in 0..=186 {
+            assert_eq!(
+                fibonacci_big(index),
+                BigUint::from(fibonacci_u128(index).expect("Fibonacci number to fit in a u128"))
+            );
+        }
+        assert_eq!(fibonacci_big(1_000).to_string().len(), 209);
+    }
+
+    #[test]
+    fn fibonacci_mod_matches_big_fibonacci() {
+        for modulus in [1, 2, 10, 1_000_000_007, u64::MAX] {
+            for index in [0, 1, 2, 50, 93, 94, 500] {
+                assert_eq!(
+                    BigUint::from(fibonacci_mod(index, modulus)),
+                    fibonacci_big(index) % modulus,
+                    "F({}) mod {}",
+                    index,
+                    modulus
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pisano_period_returns_periods() {
+        assert_eq!(
+            (1..=12).map(pisano_period).collect::<Vec<u64>>(),
+            vec![1, 3, 8, 6, 20, 24, 16, 12, 24, 60, 10, 24]
+        );
+        assert_eq!(pisano_period(1_000), 1_500);
+    }
+}
@@ -1,78 +1,35 @@
+pub mod arithmetic_functions;
+pub mod factorization;
+pub mod fibonacci;
+pub mod modular;
+pub mod primality;
+pub mod sieve;
+
 use crate::shared::Exponent;
 
 /// Check if input number is a prime number.
 /// - **input_number:** Input number to check if it is a prime number.
 /// ## Notes
 /// - **Prime Number:** A number that has exactly two factors, 1 and itself.
+/// - **Miller-Rabin:** Deterministic across all 64-bit numbers, see `primality::is_prime_u128`.
 pub fn is_prime_number(input_number: u64) -> bool {
-    if input_number == 2 {
-        return true;
-    } else if input_number < 2 || input_number % 2 == 0 {
-        return false;
-    }
-
-    let mut i = 3;
-    while i * i <= input_number {
-        if input_number % i == 0 {
-            return false;
-        }
-
-        i += 2;
-    }
-
-    true
+    primality::is_prime_u64(input_number)
 }
 
 /// Get prime factors of input number using prime factorization.
 /// - **input_number:** Input number to get prime factors for.
 /// ## Notes
 /// - **Prime Factor:** A prime number that divides evenly into a given number.
-/// - **Prime Factorization:** The process of finding the prime factors of an integer, by dividing
-///   it repeatedly by the smallest prime number that divides into it evenly.
-pub fn prime_factors(mut input_number: u64) -> Vec<Exponent> {
-    let mut prime_factors = vec![];
-
-    if input_number == 0 {
-        return prime_factors;
-    }
-
-    fn add_prime_factor(prime_factors: &mut Vec<Exponent>, base_value: u64) {
-        match prime_factors
-            .iter_mut()
-            .find(|factor| factor.base_value == base_value)
-        {
-            Some(factor) => factor.power += 1,
-            None => prime_factors.push(Exponent {
-                base_value,
-                power: 1,
-            }),
-        }
-    }
-
-    while input_number % 2 == 0 {
-        add_prime_factor(&mut prime_factors, 2);
-        input_number /= 2;
-    }
-
-    let mut i = 3;
-    loop {
-        while input_number % i == 0 {
-            add_prime_factor(&mut prime_factors, i);
-            input_number /= i;
-        }
-
-        if i as f32 > (input_number as f32).sqrt() {
-            break;
-        }
-
-        i += 2;
-    }
-
-    if input_number > 2 {
-        add_prime_factor(&mut prime_factors, input_number);
-    }
-
-    prime_factors
+/// - **Prime Factorization:** The process of finding the prime factors of an integer, see
+///   `factorization::prime_factors_u128`.
+pub fn prime_factors(input_number: u64) -> Vec<Exponent> {
+    factorization::prime_factors_u128(input_number.into())
+        .into_iter()
+        .map(|prime_factor| Exponent {
+            base_value: prime_factor.base_value as u64,
+            power: prime_factor.power,
+        })
+        .collect()
 }
 
 /// Get proper divisors of input number.
@@ -83,7 +40,7 @@ pub fn proper_divisors(input_number: u64) -> Vec<u64> {
     let mut proper_divisors = vec![];
 
     for i in 1..=(input_number as f64).sqrt().floor() as u64 {
-        if input_number % i == 0 {
+        if input_number.is_multiple_of(i) {
             proper_divisors.push(i);
 
             if input_number / i != i && i != 1 {
@@ -99,11 +56,8 @@ pub fn proper_divisors(input_number: u64) -> Vec<u64> {
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
     use super::{is_prime_number, prime_factors, proper_divisors};
     use crate::shared::Exponent;
-    use test::Bencher;
 
     #[test]
     fn is_prime_number_returns_true_for_prime_numbers() {
@@ -119,7 +73,7 @@ mod tests {
         let composite_numbers: Vec<u64> = vec![1, 4, 6, 8, 9, 10, 12, 14, 15, 16, 18];
 
         for composite_number in composite_numbers {
-            assert!(is_prime_number(composite_number) == false);
+            assert!(!is_prime_number(composite_number));
         }
     }
 
@@ -151,19 +105,4 @@ mod tests {
             vec![1, 2, 4, 5, 10, 11, 20, 22, 44, 55, 110]
         );
     }
-
-    #[bench]
-    fn bench_is_prime_number_using_one_millionth_prime_number(bencher: &mut Bencher) {
-        bencher.iter(|| is_prime_number(15_485_863));
-    }
-
-    #[bench]
-    fn bench_prime_factors_using_one_millionth_prime_number(bencher: &mut Bencher) {
-        bencher.iter(|| prime_factors(15_485_863));
-    }
-
-    #[bench]
-    fn bench_proper_divisors_using_one_million(bencher: &mut Bencher) {
-        bencher.iter(|| proper_divisors(1_000_000));
-    }
 }
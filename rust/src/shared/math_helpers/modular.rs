@@ -0,0 +1,298 @@
+/// Multiply two numbers modulo a modulus, without overflowing.
+/// - **left:** Left operand, less than the modulus.
+/// - **right:** Right operand, less than the modulus.
+/// - **modulus:** Non-zero modulus.
+pub fn mod_mul(left: u64, right: u64, modulus: u64) -> u64 {
+    (u128::from(left) * u128::from(right) % u128::from(modulus)) as u64
+}
+
+/// Raise a number to a power modulo a modulus, using exponentiation by squaring.
+/// - **base_value:** Number to raise to the power.
+/// - **power:** Power to raise the number to.
+/// - **modulus:** Non-zero modulus.
+pub fn mod_pow(base_value: u64, mut power: u64, modulus: u64) -> u64 {
+    let mut base_value = base_value % modulus;
+    let mut result = 1 % modulus;
+
+    while power > 0 {
+        if power & 1 == 1 {
+            result = mod_mul(result, base_value, modulus);
+        }
+
+        base_value = mod_mul(base_value, base_value, modulus);
+        power >>= 1;
+    }
+
+    result
+}
+
+/// Add two numbers modulo a modulus, without overflowing.
+/// - **left:** Left operand, less than the modulus.
+/// - **right:** Right operand, less than the modulus.
+/// - **modulus:** Non-zero modulus.
+pub fn mod_add_u128(left: u128, right: u128, modulus: u128) -> u128 {
+    if left >= modulus - right {
+        left - (modulus - right)
+    } else {
+        left + right
+    }
+}
+
+/// Multiply two numbers modulo a modulus, without overflowing.
+/// - **left:** Left operand, less than the modulus.
+/// - **right:** Right operand, less than the modulus.
+/// - **modulus:** Non-zero modulus.
+/// ## Notes
+/// - **Double and Add:** Moduli wider than 64 bits are multiplied one bit of the right operand at
+///   a time, since there is no wider integer type to hold the full product.
+pub fn mod_mul_u128(left: u128, right: u128, modulus: u128) -> u128 {
+    if let (Ok(left), Ok(right), Ok(modulus)) = (
+        u64::try_from(left),
+        u64::try_from(right),
+        u64::try_from(modulus),
+    ) {
+        return u128::from(mod_mul(left, right, modulus));
+    }
+
+    if let (Ok(left), Ok(right)) = (u64::try_from(left), u64::try_from(right)) {
+        return u128::from(left) * u128::from(right) % modulus;
+    }
+
+    let mut result = 0;
+    for bit in (0..(u128::BITS - right.leading_zeros())).rev() {
+        result = mod_add_u128(result, result, modulus);
+
+        if (right >> bit) & 1 == 1 {
+            result = mod_add_u128(result, left, modulus);
+        }
+    }
+
+    result
+}
+
+/// Raise a number to a power modulo a modulus, using exponentiation by squaring.
+/// - **base_value:** Number to raise to the power.
+/// - **power:** Power to raise the number to.
+/// - **modulus:** Non-zero modulus.
+pub fn mod_pow_u128(base_value: u128, mut power: u128, modulus: u128) -> u128 {
+    let mut base_value = base_value % modulus;
+    let mut result = 1 % modulus;
+
+    while power > 0 {
+        if power & 1 == 1 {
+            result = mod_mul_u128(result, base_value, modulus);
+        }
+
+        base_value = mod_mul_u128(base_value, base_value, modulus);
+        power >>= 1;
+    }
+
+    result
+}
+
+/// Get greatest common divisor of two numbers, with Bézout coefficients.
+/// - **left:** Left number.
+/// - **right:** Right number.
+/// ## Notes
+/// - **Extended Euclidean Algorithm:** Returns (g, x, y) where g = gcd(left, right) and
+///   left × x + right × y = g.
+pub fn extended_gcd(left: i128, right: i128) -> (i128, i128, i128) {
+    let (mut previous_remainder, mut remainder) = (left, right);
+    let (mut previous_x, mut x) = (1, 0);
+    let (mut previous_y, mut y) = (0, 1);
+
+    while remainder != 0 {
+        let quotient = previous_remainder / remainder;
+
+        (previous_remainder, remainder) = (remainder, previous_remainder - quotient * remainder);
+        (previous_x, x) = (x, previous_x - quotient * x);
+        (previous_y, y) = (y, previous_y - quotient * y);
+    }
+
+    if previous_remainder < 0 {
+        (-previous_remainder, -previous_x, -previous_y)
+    } else {
+        (previous_remainder, previous_x, previous_y)
+    }
+}
+
+/// Get modular multiplicative inverse of a number, if it is coprime to the modulus.
+/// - **value:** Number to get the inverse of.
+/// - **modulus:** Non-zero modulus.
+/// ## Notes
+/// - **Modular Inverse:** The number x where value × x ≡ 1 (mod modulus).
+pub fn mod_inverse(value: u64, modulus: u64) -> Option<u64> {
+    let (gcd, x, _) = extended_gcd(i128::from(value % modulus), i128::from(modulus));
+
+    (gcd == 1).then(|| x.rem_euclid(i128::from(modulus)) as u64)
+}
+
+/// Solve a system of congruences, if it has a solution.
+/// - **congruences:** Congruences as residue and non-zero modulus pairs.
+/// ## Notes
+/// - **Chinese Remainder Theorem:** Returns (x, m) where every solution is x + km, with m the
+///   least common multiple of the moduli. Moduli do not need to be coprime.
+/// - Returns `None` if the congruences contradict each other or m does not fit in a `u64`.
+pub fn chinese_remainder(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), &(next_residue, next_modulus)| {
+            let next_residue = next_residue % next_modulus;
+            let (gcd, inverse, _) = extended_gcd(i128::from(modulus), i128::from(next_modulus));
+            let difference = i128::from(next_residue) - i128::from(residue);
+
+            if difference % gcd != 0 {
+                return None;
+            }
+
+            let reduced_modulus = i128::from(next_modulus) / gcd;
+            let step = (difference / gcd).rem_euclid(reduced_modulus) as u128
+                * inverse.rem_euclid(reduced_modulus) as u128
+                % reduced_modulus as u128;
+            let combined_modulus = (modulus / gcd as u64).checked_mul(next_modulus)?;
+            let combined_residue =
+                (u128::from(residue) + u128::from(modulus) * step) % u128::from(combined_modulus);
+
+            Some((combined_residue as u64, combined_modulus))
+        },
+    )
+}
+
+/// Get square root of a number modulo a prime number, if it has one.
+/// - **value:** Number to get the square root of.
+/// - **prime:** Prime modulus.
+/// ## Notes
+/// - **Tonelli-Shanks:** Finds x where x² ≡ value (mod prime). The other root is prime - x, and
+///   the smaller of the two is returned.
+/// - **Euler's Criterion:** A non-zero value has a square root exactly when
+///   value^((prime - 1) / 2) ≡ 1 (mod prime).
+pub fn mod_sqrt(value: u64, prime: u64) -> Option<u64> {
+    let value = value % prime;
+    if value == 0 || prime == 2 {
+        return Some(value);
+    } else if mod_pow(value, (prime - 1) / 2, prime) != 1 {
+        return None;
+    }
+
+    let power_of_two = (prime - 1).trailing_zeros();
+    let odd_factor = (prime - 1) >> power_of_two;
+    let non_residue = (2..prime)
+        .find(|&candidate| mod_pow(candidate, (prime - 1) / 2, prime) == prime - 1)
+        .expect("odd prime to have a quadratic non-residue");
+
+    let mut order = power_of_two;
+    let mut correction = mod_pow(non_residue, odd_factor, prime);
+    let mut remainder = mod_pow(value, odd_factor, prime);
+    let mut root = mod_pow(value, odd_factor.div_ceil(2), prime);
+
+    while remainder != 1 {
+        let mut remainder_order = 0;
+        let mut squared_remainder = remainder;
+        while squared_remainder != 1 {
+            squared_remainder = mod_mul(squared_remainder, squared_remainder, prime);
+            remainder_order += 1;
+        }
+
+        let factor = mod_pow(correction, 1 << (order - remainder_order - 1), prime);
+        order = remainder_order;
+        correction = mod_mul(factor, factor, prime);
+        remainder = mod_mul(remainder, correction, prime);
+        root = mod_mul(root, factor, prime);
+    }
+
+    Some(root.min(prime - root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        chinese_remainder, extended_gcd, mod_inverse, mod_mul, mod_mul_u128, mod_pow, mod_pow_u128,
+        mod_sqrt,
+    };
+
+    #[test]
+    fn mod_mul_does_not_overflow() {
+        assert_eq!(mod_mul(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+        assert_eq!(mod_mul(7, 8, 5), 1);
+    }
+
+    #[test]
+    fn mod_pow_raises_to_powers() {
+        assert_eq!(mod_pow(2, 10, 1_000), 24);
+        assert_eq!(mod_pow(3, 0, 7), 1);
+        assert_eq!(mod_pow(3, 5, 1), 0);
+        assert_eq!(mod_pow(u64::MAX - 1, 3, u64::MAX), u64::MAX - 1);
+    }
+
+    #[test]
+    fn mod_mul_u128_does_not_overflow() {
+        assert_eq!(mod_mul_u128(u128::MAX - 1, u128::MAX - 1, u128::MAX), 1);
+        assert_eq!(
+            mod_mul_u128(1 << 100, 1 << 100, (1 << 127) + 1),
+            (1 << 127) + 1 - (1 << 73)
+        );
+        assert_eq!(mod_mul_u128(7, 8, 5), 1);
+    }
+
+    #[test]
+    fn mod_pow_u128_raises_to_powers() {
+        assert_eq!(mod_pow_u128(2, 127, u128::MAX), 1 << 127);
+        assert_eq!(mod_pow_u128(2, 128, u128::MAX), 1);
+        assert_eq!(mod_pow_u128(10, 10, 1_000_000_007), 999_999_937);
+    }
+
+    #[test]
+    fn extended_gcd_returns_bezout_coefficients() {
+        for (left, right) in [(240, 46), (46, 240), (17, 5), (0, 7), (-12, 18)] {
+            let (gcd, x, y) = extended_gcd(left, right);
+
+            assert!(gcd >= 0);
+            assert_eq!(left * x + right * y, gcd);
+        }
+        assert_eq!(extended_gcd(240, 46).0, 2);
+    }
+
+    #[test]
+    fn mod_inverse_inverts_coprime_numbers() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(
+            mod_mul(
+                mod_inverse(123_456_789, 1_000_000_007).expect("number to be invertible"),
+                123_456_789,
+                1_000_000_007
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn chinese_remainder_solves_congruences() {
+        assert_eq!(
+            chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        assert_eq!(chinese_remainder(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+        assert_eq!(chinese_remainder(&[(1, 1 << 40), (0, 3 << 30)]), None);
+        assert_eq!(chinese_remainder(&[(0, u64::MAX), (0, 2)]), None);
+    }
+
+    #[test]
+    fn mod_sqrt_finds_square_roots() {
+        assert_eq!(mod_sqrt(10, 13), Some(6));
+        assert_eq!(mod_sqrt(5, 13), None);
+        assert_eq!(mod_sqrt(0, 13), Some(0));
+
+        for prime in [17, 97, 257, 65_537, 1_000_000_007] {
+            for value in 1..200 {
+                match mod_sqrt(value, prime) {
+                    Some(root) => assert_eq!(mod_mul(root, root, prime), value % prime),
+                    None => assert_eq!(mod_pow(value, (prime - 1) / 2, prime), prime - 1),
+                }
+            }
+        }
+    }
+}
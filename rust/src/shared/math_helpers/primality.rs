@@ -0,0 +1,264 @@
+use crate::shared::math_helpers::modular::{mod_mul, mod_mul_u128, mod_pow, mod_pow_u128};
+use num::{BigUint, Integer, One, Zero};
+
+/// Prime numbers trial divided by before running Miller-Rabin, and used as its witnesses above
+/// the 64-bit range.
+const SMALL_PRIMES: [u64; 20] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+];
+
+/// Miller-Rabin witnesses that correctly classify every 64-bit number.
+/// ## Notes
+/// - **Witness Set:** Found by Jim Sinclair, proven to have no strong pseudoprimes below 2^64.
+const U64_WITNESSES: [u64; 7] = [2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022];
+
+/// Split odd input number minus one into an odd factor and a power of two.
+/// - **input_number:** Odd input number greater than 2.
+fn split_power_of_two(input_number: u128) -> (u128, u32) {
+    let input_number_minus_one = input_number - 1;
+    let power_of_two = input_number_minus_one.trailing_zeros();
+
+    (input_number_minus_one >> power_of_two, power_of_two)
+}
+
+/// Check input number against small prime numbers.
+/// - **input_number:** Input number to check.
+/// ## Notes
+/// - Returns `None` if the input number has no small prime factor and needs further testing.
+fn check_small_primes(input_number: u128) -> Option<bool> {
+    if input_number < 2 {
+        return Some(false);
+    }
+
+    for &prime in SMALL_PRIMES.iter() {
+        let prime = u128::from(prime);
+
+        if input_number == prime {
+            return Some(true);
+        } else if input_number.is_multiple_of(prime) {
+            return Some(false);
+        }
+    }
+
+    None
+}
+
+/// Check if a 64-bit input number is a prime number using deterministic Miller-Rabin.
+/// - **input_number:** Input number to check if it is a prime number.
+pub(super) fn is_prime_u64(input_number: u64) -> bool {
+    if let Some(is_prime) = check_small_primes(u128::from(input_number)) {
+        return is_prime;
+    }
+
+    let (odd_factor, power_of_two) = split_power_of_two(u128::from(input_number));
+    let odd_factor = odd_factor as u64;
+
+    U64_WITNESSES.iter().all(|&witness| {
+        let witness = witness % input_number;
+        if witness == 0 {
+            return true;
+        }
+
+        let mut remainder = mod_pow(witness, odd_factor, input_number);
+        if remainder == 1 || remainder == input_number - 1 {
+            return true;
+        }
+
+        for _ in 1..power_of_two {
+            remainder = mod_mul(remainder, remainder, input_number);
+            if remainder == input_number - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Check if a 128-bit input number is a prime number using Miller-Rabin.
+/// - **input_number:** Input number to check if it is a prime number.
+/// ## Notes
+/// - **Miller-Rabin:** A composite number fails the test for at least three quarters of witnesses.
+///   Numbers in the 64-bit range use a witness set proven to be exact, larger numbers use the
+///   first 20 prime numbers, which is proven exact below 3.3 × 10^24 and has no known
+///   counterexample above it.
+pub fn is_prime_u128(input_number: u128) -> bool {
+    if let Ok(input_number) = u64::try_from(input_number) {
+        return is_prime_u64(input_number);
+    } else if let Some(is_prime) = check_small_primes(input_number) {
+        return is_prime;
+    }
+
+    let (odd_factor, power_of_two) = split_power_of_two(input_number);
+
+    SMALL_PRIMES.iter().all(|&witness| {
+        let mut remainder = mod_pow_u128(u128::from(witness), odd_factor, input_number);
+        if remainder == 1 || remainder == input_number - 1 {
+            return true;
+        }
+
+        for _ in 1..power_of_two {
+            remainder = mod_mul_u128(remainder, remainder, input_number);
+            if remainder == input_number - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Check if an arbitrarily large input number is probably a prime number using Miller-Rabin.
+/// - **input_number:** Input number to check if it is a prime number.
+/// - **rounds:** Number of pseudo-random witnesses to test, on top of the small prime witnesses.
+/// ## Notes
+/// - **Error Bound:** A composite number passes each pseudo-random round with probability at most
+///   1/4, so it is reported as prime with probability at most 4^-rounds.
+/// - Witnesses are derived from the input number, so results are reproducible.
+pub fn is_probable_prime(input_number: &BigUint, rounds: usize) -> bool {
+    if let Ok(input_number) = u128::try_from(input_number) {
+        return is_prime_u128(input_number);
+    } else if SMALL_PRIMES
+        .iter()
+        .any(|&prime| (input_number % prime).is_zero())
+    {
+        return false;
+    }
+
+    let input_number_minus_one = input_number - 1_u32;
+    let power_of_two = input_number_minus_one
+        .trailing_zeros()
+        .expect("input number minus one to be non-zero");
+    let odd_factor = &input_number_minus_one >> power_of_two;
+
+    let is_witness_passed = |witness: &BigUint| {
+        let mut remainder = witness.modpow(&odd_factor, input_number);
+        if remainder.is_one() || remainder == input_number_minus_one {
+            return true;
+        }
+
+        for _ in 1..power_of_two {
+            remainder = &remainder * &remainder % input_number;
+            if remainder == input_number_minus_one {
+                return true;
+            } else if remainder.is_one() {
+                return false;
+            }
+        }
+
+        false
+    };
+
+    if !SMALL_PRIMES
+        .iter()
+        .all(|&witness| is_witness_passed(&BigUint::from(witness)))
+    {
+        return false;
+    }
+
+    let mut seed = input_number.iter_u64_digits().fold(0, u64::wrapping_add);
+    let witness_range = input_number - 3_u32;
+    let witness_digits = input_number.iter_u32_digits().count() + 1;
+
+    (0..rounds).all(|_| {
+        let random_number = BigUint::new(
+            (0..witness_digits)
+                .map(|_| split_mix(&mut seed) as u32)
+                .collect(),
+        );
+
+        is_witness_passed(&(random_number.mod_floor(&witness_range) + 2_u32))
+    })
+}
+
+/// Advance a SplitMix64 pseudo-random number generator, returning its next output.
+/// - **state:** Generator state to advance.
+pub(crate) fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut output = *state;
+    output = (output ^ (output >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    output = (output ^ (output >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    output ^ (output >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_prime_u128, is_prime_u64, is_probable_prime};
+    use num::BigUint;
+
+    /// Check if input number is a prime number using trial division.
+    fn is_prime_by_trial_division(input_number: u64) -> bool {
+        input_number >= 2
+            && (2..)
+                .take_while(|divisor| divisor * divisor <= input_number)
+                .all(|divisor| !input_number.is_multiple_of(divisor))
+    }
+
+    #[test]
+    fn is_prime_u64_matches_trial_division() {
+        assert!(
+            (0..100_000).all(|number| is_prime_u64(number) == is_prime_by_trial_division(number))
+        );
+    }
+
+    #[test]
+    fn is_prime_u64_rejects_strong_pseudoprimes() {
+        for pseudoprime in [
+            2_047,
+            1_373_653,
+            25_326_001,
+            3_215_031_751,
+            2_152_302_898_747,
+            3_474_749_660_383,
+            341_550_071_728_321,
+            3_825_123_056_546_413_051,
+        ] {
+            assert!(!is_prime_u64(pseudoprime), "{}", pseudoprime);
+        }
+    }
+
+    #[test]
+    fn is_prime_u64_handles_numbers_near_u64_max() {
+        assert!(is_prime_u64(18_446_744_073_709_551_557));
+        assert!(!is_prime_u64(u64::MAX));
+        assert!(!is_prime_u64(4_294_967_291 * 4_294_967_279));
+    }
+
+    #[test]
+    fn is_prime_u128_handles_large_numbers() {
+        assert!(is_prime_u128(u128::MAX - 158));
+        assert!(is_prime_u128((1 << 127) - 1));
+        assert!(is_prime_u128(u128::from(u64::MAX) + 14));
+        assert!(!is_prime_u128(u128::MAX));
+        assert!(!is_prime_u128(
+            u128::from(18_446_744_073_709_551_557_u64) * u128::from(18_446_744_073_709_551_533_u64)
+        ));
+        assert!(!is_prime_u128(318_665_857_834_031_151_167_461));
+        assert!(!is_prime_u128(3_317_044_064_679_887_385_961_981));
+    }
+
+    #[test]
+    fn is_probable_prime_matches_u128_test() {
+        for number in (0..2_000).chain([(1 << 89) - 1, (1 << 127) - 1, (1 << 127) + 1]) {
+            assert_eq!(
+                is_probable_prime(&BigUint::from(number), 10),
+                is_prime_u128(number),
+                "{}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn is_probable_prime_handles_numbers_beyond_u128() {
+        let two = BigUint::from(2_u32);
+
+        assert!(is_probable_prime(&(two.pow(521) - 1_u32), 10));
+        assert!(!is_probable_prime(&(two.pow(523) - 1_u32), 10));
+        assert!(!is_probable_prime(
+            &((two.pow(521) - 1_u32) * (two.pow(607) - 1_u32)),
+            10
+        ));
+    }
+}
@@ -0,0 +1,361 @@
+use std::vec;
+
+/// Number of bits in a sieve word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Number of odd numbers in the first segment of a segmented prime iterator.
+const INITIAL_SEGMENT_LENGTH: usize = 1 << 12;
+
+/// Maximum number of odd numbers in a segment, sized so a segment's words fit in a CPU cache.
+const SEGMENT_LENGTH: usize = 1 << 18;
+
+/// Bit-packed sieve of the odd numbers in a range, where a set bit marks a prime number.
+struct OddSegment {
+    /// First odd number of the segment.
+    start: u64,
+
+    /// Sieve words, where bit `i` represents the odd number `start + 2i`.
+    words: Vec<u64>,
+}
+
+impl OddSegment {
+    /// Sieve the odd numbers in a range.
+    /// - **start:** Inclusive range start.
+    /// - **end:** Exclusive range end.
+    /// - **base_primes:** Ascending prime numbers, including every prime number up to the square
+    ///   root of the range end.
+    fn new(start: u64, end: u64, base_primes: &[u64]) -> Self {
+        let start = start | 1;
+        let length = if end > start {
+            ((end - start).div_ceil(2)) as usize
+        } else {
+            0
+        };
+
+        let mut words = vec![u64::MAX; length.div_ceil(WORD_BITS)];
+        if !length.is_multiple_of(WORD_BITS) {
+            if let Some(last_word) = words.last_mut() {
+                *last_word = (1 << (length % WORD_BITS)) - 1;
+            }
+        }
+
+        if start == 1 && length > 0 {
+            words[0] &= !1;
+        }
+
+        for &prime in base_primes.iter().skip_while(|&&prime| prime == 2) {
+            let Some(prime_square) = prime.checked_mul(prime) else {
+                break;
+            };
+            if prime_square >= end {
+                break;
+            }
+
+            let first_multiple = match start.div_ceil(prime).checked_mul(prime) {
+                Some(first_multiple) => first_multiple.max(prime_square),
+                None => continue,
+            };
+            let first_multiple = if first_multiple.is_multiple_of(2) {
+                match first_multiple.checked_add(prime) {
+                    Some(first_multiple) => first_multiple,
+                    None => continue,
+                }
+            } else {
+                first_multiple
+            };
+
+            let mut index = ((first_multiple - start) / 2) as usize;
+            while index < length {
+                words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+                index += prime as usize;
+            }
+        }
+
+        Self { start, words }
+    }
+
+    /// Get number of prime numbers in the segment.
+    fn prime_count(&self) -> u64 {
+        self.words
+            .iter()
+            .map(|word| u64::from(word.count_ones()))
+            .sum()
+    }
+
+    /// Get prime numbers in the segment, in ascending order.
+    fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(word_index, &word)| {
+                let mut word = word;
+
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+
+                    let bit_index = word.trailing_zeros() as usize;
+                    word &= word - 1;
+
+                    Some(self.start + 2 * (word_index * WORD_BITS + bit_index) as u64)
+                })
+            })
+    }
+}
+
+/// Get prime numbers up to and including the square root of a range end, for sieving the range.
+/// - **end:** Exclusive range end.
+fn base_primes(end: u64) -> Vec<u64> {
+    let root = end.saturating_sub(1).isqrt();
+
+    if root < 3 {
+        vec![]
+    } else {
+        PrimeSieve::new(root + 1).primes().collect()
+    }
+}
+
+/// Sieve a range in segments, calling back with each segment.
+/// - **start:** Inclusive range start.
+/// - **end:** Exclusive range end.
+/// - **on_segment:** Callback for each sieved segment, in ascending order.
+fn sieve_segments(start: u64, end: u64, mut on_segment: impl FnMut(&OddSegment)) {
+    let base_primes = base_primes(end);
+    let mut segment_start = start;
+
+    while segment_start < end {
+        let segment_end = segment_start
+            .saturating_add(2 * SEGMENT_LENGTH as u64)
+            .min(end);
+
+        on_segment(&OddSegment::new(segment_start, segment_end, &base_primes));
+
+        segment_start = segment_end;
+    }
+}
+
+/// Get prime numbers below a limit, in ascending order.
+/// - **limit:** Exclusive upper bound of the prime numbers.
+pub fn primes_below(limit: u64) -> Vec<u64> {
+    primes_in_range(0, limit)
+}
+
+/// Get prime numbers in a range, in ascending order, using a segmented sieve.
+/// - **start:** Inclusive range start.
+/// - **end:** Exclusive range end.
+pub fn primes_in_range(start: u64, end: u64) -> Vec<u64> {
+    let mut primes = vec![];
+
+    if start <= 2 && end > 2 {
+        primes.push(2);
+    }
+
+    sieve_segments(start, end, |segment| primes.extend(segment.primes()));
+
+    primes
+}
+
+/// Count prime numbers below a limit using a segmented sieve, without storing them.
+/// - **limit:** Exclusive upper bound of the prime numbers.
+/// ## Notes
+/// - **Prime Counting Function:** π(n), the number of prime numbers less than or equal to n.
+pub fn count_primes_below(limit: u64) -> u64 {
+    let mut prime_count = u64::from(limit > 2);
+
+    sieve_segments(0, limit, |segment| prime_count += segment.prime_count());
+
+    prime_count
+}
+
+/// Sieve of Eratosthenes over the numbers below a limit, storing odd numbers one bit each.
+/// ## Notes
+/// - **Sieve of Eratosthenes:** Finds prime numbers by crossing out the multiples of each prime
+///   number, starting from its square.
+pub struct PrimeSieve {
+    /// Exclusive upper bound of the sieved numbers.
+    limit: u64,
+
+    /// Sieved odd numbers below the limit.
+    segment: OddSegment,
+}
+
+impl PrimeSieve {
+    /// Sieve the numbers below a limit.
+    /// - **limit:** Exclusive upper bound of the sieved numbers.
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            segment: OddSegment::new(0, limit, &base_primes(limit)),
+        }
+    }
+
+    /// Get exclusive upper bound of the sieved numbers.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Check if input number is a prime number in constant time.
+    /// - **input_number:** Input number to check if it is a prime number, below the sieve limit.
+    pub fn is_prime(&self, input_number: u64) -> bool {
+        assert!(
+            input_number < self.limit,
+            "{} is not below the sieve limit {}",
+            input_number,
+            self.limit
+        );
+
+        if input_number.is_multiple_of(2) {
+            return input_number == 2;
+        }
+
+        let index = (input_number / 2) as usize;
+        self.segment.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Get prime numbers below the sieve limit, in ascending order.
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        (self.limit > 2)
+            .then_some(2)
+            .into_iter()
+            .chain(self.segment.primes())
+    }
+
+    /// Get number of prime numbers below the sieve limit.
+    pub fn prime_count(&self) -> u64 {
+        u64::from(self.limit > 2) + self.segment.prime_count()
+    }
+}
+
+/// Unbounded prime numbers iterator, sieving segments of growing length as it goes.
+pub struct SegmentedPrimes {
+    /// Prime numbers used to sieve segments, covering every prime up to the square root of the
+    /// current segment end.
+    base_primes: Vec<u64>,
+
+    /// Exclusive end of the ranges the base prime numbers are enough to sieve.
+    base_primes_end: u64,
+
+    /// Start of the next segment to sieve.
+    next_segment_start: u64,
+
+    /// Number of odd numbers in the next segment to sieve.
+    next_segment_length: usize,
+
+    /// Prime numbers of the current segment yet to be returned.
+    primes: vec::IntoIter<u64>,
+}
+
+impl Default for SegmentedPrimes {
+    fn default() -> Self {
+        Self {
+            base_primes: vec![],
+            base_primes_end: 9,
+            next_segment_start: 3,
+            next_segment_length: INITIAL_SEGMENT_LENGTH,
+            primes: vec![2].into_iter(),
+        }
+    }
+}
+
+impl Iterator for SegmentedPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(prime) = self.primes.next() {
+                return Some(prime);
+            }
+
+            let segment_start = self.next_segment_start;
+            if segment_start == u64::MAX {
+                return None;
+            }
+
+            let segment_end = segment_start.saturating_add(2 * self.next_segment_length as u64);
+            if segment_end > self.base_primes_end {
+                self.base_primes_end = segment_end.saturating_mul(4);
+                self.base_primes = base_primes(self.base_primes_end);
+            }
+
+            self.primes = OddSegment::new(segment_start, segment_end, &self.base_primes)
+                .primes()
+                .collect::<Vec<u64>>()
+                .into_iter();
+            self.next_segment_start = segment_end;
+            self.next_segment_length = (self.next_segment_length * 2).min(SEGMENT_LENGTH);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_primes_below, primes_below, primes_in_range, PrimeSieve, SegmentedPrimes};
+    use crate::shared::math_helpers::is_prime_number;
+
+    #[test]
+    fn primes_below_returns_primes_in_ascending_order() {
+        assert_eq!(primes_below(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_below(3), vec![2]);
+        assert!(primes_below(2).is_empty());
+    }
+
+    #[test]
+    fn primes_in_range_matches_trial_division_across_segments() {
+        let start = 999_000;
+        let end = 2_100_000;
+
+        assert_eq!(
+            primes_in_range(start, end),
+            (start..end)
+                .filter(|&number| is_prime_number(number))
+                .collect::<Vec<u64>>()
+        );
+    }
+
+    #[test]
+    fn primes_in_range_handles_ranges_of_large_numbers() {
+        let start = 1_000_000_000_000;
+        let end = start + 1_000;
+
+        assert_eq!(
+            primes_in_range(start, end),
+            (start..end)
+                .filter(|&number| is_prime_number(number))
+                .collect::<Vec<u64>>()
+        );
+    }
+
+    #[test]
+    fn count_primes_below_returns_prime_counting_function() {
+        assert_eq!(count_primes_below(0), 0);
+        assert_eq!(count_primes_below(3), 1);
+        assert_eq!(count_primes_below(100), 25);
+        assert_eq!(count_primes_below(10_000_000), 664_579);
+    }
+
+    #[test]
+    fn prime_sieve_looks_up_primes() {
+        let prime_sieve = PrimeSieve::new(1_000);
+
+        assert!((0..1_000).all(|number| prime_sieve.is_prime(number) == is_prime_number(number)));
+        assert_eq!(prime_sieve.prime_count(), 168);
+        assert_eq!(prime_sieve.primes().last(), Some(997));
+    }
+
+    #[test]
+    #[should_panic]
+    fn prime_sieve_rejects_lookups_at_limit() {
+        PrimeSieve::new(100).is_prime(100);
+    }
+
+    #[test]
+    fn segmented_primes_matches_sieved_primes() {
+        assert_eq!(
+            SegmentedPrimes::default()
+                .take_while(|&prime| prime < 3_000_000)
+                .collect::<Vec<u64>>(),
+            primes_below(3_000_000)
+        );
+    }
+}
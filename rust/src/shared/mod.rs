@@ -1,10 +1,21 @@
 mod exponent;
+mod factorization;
+mod from_english;
+mod linear_recurrence;
+mod mod_int;
 mod to_english;
 
+pub mod collatz;
+pub mod combinatorics;
+pub mod digits;
 pub mod file_helpers;
 pub mod iterators;
 pub mod math_helpers;
 pub mod string_helpers;
 
 pub use exponent::Exponent;
+pub use factorization::{Divisors, Factorization};
+pub use from_english::{FromEnglish, ParseEnglishError};
+pub use linear_recurrence::{LinearRecurrence, LinearRecurrenceTerms};
+pub use mod_int::ModInt;
 pub use to_english::ToEnglish;
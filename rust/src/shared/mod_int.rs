@@ -0,0 +1,329 @@
+use crate::shared::math_helpers::modular::mod_inverse;
+use num::{CheckedAdd, CheckedMul, One, Zero};
+use std::{
+    fmt::{self, Debug, Display},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Represents an integer modulo `M`.
+/// ## Notes
+/// - **Montgomery Form:** For odd moduli, values are stored as x × 2^64 mod M, so multiplication
+///   reduces with shifts and multiplications instead of a 128-bit division. Even moduli, such as
+///   10^10 for the last ten digits, are stored as is.
+/// - The modulus must be between 1 and 2^63.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ModInt<const M: u64> {
+    /// Value, in Montgomery form if the modulus is odd.
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    /// Whether values are stored in Montgomery form.
+    const IS_MONTGOMERY: bool = {
+        assert!(M > 0 && M <= 1 << 63, "modulus must be between 1 and 2^63");
+        M % 2 == 1
+    };
+
+    /// Negated inverse of the modulus modulo 2^64, found with Newton's iteration.
+    const NEGATED_INVERSE: u64 = {
+        let mut inverse = M;
+        let mut iteration = 0;
+        while iteration < 5 {
+            inverse = inverse.wrapping_mul(2_u64.wrapping_sub(M.wrapping_mul(inverse)));
+            iteration += 1;
+        }
+        inverse.wrapping_neg()
+    };
+
+    /// 2^128 modulo the modulus, for converting values into Montgomery form.
+    const R_SQUARED: u64 = {
+        let r = (u64::MAX % M + 1) % M;
+        ((r as u128 * r as u128) % M as u128) as u64
+    };
+
+    /// Get modular integer of a value.
+    /// - **value:** Value to reduce modulo the modulus.
+    pub fn new(value: u64) -> Self {
+        if Self::IS_MONTGOMERY {
+            Self {
+                value: Self::reduce(u128::from(value % M) * u128::from(Self::R_SQUARED)),
+            }
+        } else {
+            Self { value: value % M }
+        }
+    }
+
+    /// Get value, between 0 and the modulus.
+    pub fn value(&self) -> u64 {
+        if Self::IS_MONTGOMERY {
+            Self::reduce(u128::from(self.value))
+        } else {
+            self.value
+        }
+    }
+
+    /// Get modulus.
+    pub fn modulus() -> u64 {
+        M
+    }
+
+    /// Divide a product of values in Montgomery form by 2^64 modulo the modulus.
+    /// - **product:** Product less than the modulus times 2^64.
+    /// ## Notes
+    /// - **Montgomery Reduction:** Adds the multiple of the modulus that clears the low 64 bits,
+    ///   then shifts them out.
+    fn reduce(product: u128) -> u64 {
+        let multiple = (product as u64).wrapping_mul(Self::NEGATED_INVERSE);
+        let reduced = ((product + u128::from(multiple) * u128::from(M)) >> 64) as u64;
+
+        if reduced >= M {
+            reduced - M
+        } else {
+            reduced
+        }
+    }
+
+    /// Raise to a power, using exponentiation by squaring.
+    /// - **power:** Power to raise the value to.
+    pub fn pow(self, mut power: u64) -> Self {
+        let mut base_value = self;
+        let mut result = Self::new(1);
+
+        while power > 0 {
+            if power & 1 == 1 {
+                result *= base_value;
+            }
+
+            base_value *= base_value;
+            power >>= 1;
+        }
+
+        result
+    }
+
+    /// Get multiplicative inverse, if the value is coprime to the modulus.
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.value(), M).map(Self::new)
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> Display for ModInt<M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.value(), formatter)
+    }
+}
+
+impl<const M: u64> Debug for ModInt<M> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} (mod {})", self.value(), M)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let sum = self.value + other.value;
+
+        Self {
+            value: if sum >= M { sum - M } else { sum },
+        }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            value: if self.value >= other.value {
+                self.value - other.value
+            } else {
+                self.value + M - other.value
+            },
+        }
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let product = u128::from(self.value) * u128::from(other.value);
+
+        Self {
+            value: if Self::IS_MONTGOMERY {
+                Self::reduce(product)
+            } else {
+                (product % u128::from(M)) as u64
+            },
+        }
+    }
+}
+
+impl<const M: u64> Div for ModInt<M> {
+    type Output = Self;
+
+    /// ## Notes
+    /// - Panics if the divisor is not coprime to the modulus.
+    fn div(self, other: Self) -> Self::Output {
+        let inverse = other
+            .inverse()
+            .expect("divisor to be coprime to the modulus");
+
+        Mul::mul(self, inverse)
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::default() - self
+    }
+}
+
+impl<const M: u64> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const M: u64> SubAssign for ModInt<M> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const M: u64> MulAssign for ModInt<M> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const M: u64> DivAssign for ModInt<M> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const M: u64> Zero for ModInt<M> {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const M: u64> One for ModInt<M> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> CheckedAdd for ModInt<M> {
+    /// ## Notes
+    /// - Modular addition wraps around instead of overflowing, so it always succeeds.
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(*self + *other)
+    }
+}
+
+impl<const M: u64> CheckedMul for ModInt<M> {
+    /// ## Notes
+    /// - Modular multiplication wraps around instead of overflowing, so it always succeeds.
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(*self * *other)
+    }
+}
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModInt;
+    use crate::shared::math_helpers::modular::mod_pow;
+
+    type Prime = ModInt<1_000_000_007>;
+    type LastTenDigits = ModInt<10_000_000_000>;
+    type LargeOdd = ModInt<{ (1 << 63) - 25 }>;
+
+    #[test]
+    fn arithmetic_matches_u128_arithmetic() {
+        const MODULUS: u128 = (1 << 63) - 25;
+        let values: [u64; 6] = [0, 1, 2, 999_999_999_999, (1 << 62) + 12_345, u64::MAX];
+
+        for left in values {
+            for right in values {
+                let (left_mod, right_mod) = (LargeOdd::new(left), LargeOdd::new(right));
+                let (left, right) = (u128::from(left) % MODULUS, u128::from(right) % MODULUS);
+
+                assert_eq!(
+                    u128::from((left_mod + right_mod).value()),
+                    (left + right) % MODULUS
+                );
+                assert_eq!(
+                    u128::from((left_mod - right_mod).value()),
+                    (left + MODULUS - right) % MODULUS
+                );
+                assert_eq!(
+                    u128::from((left_mod * right_mod).value()),
+                    left * right % MODULUS
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn odd_and_even_moduli_agree() {
+        let prime = (1..=20).map(Prime::new).product::<Prime>();
+        let last_ten_digits = (1..=20).map(LastTenDigits::new).product::<LastTenDigits>();
+
+        assert_eq!(prime.value(), 146_326_063);
+        assert_eq!(last_ten_digits.value(), 8_176_640_000);
+        assert_eq!(LastTenDigits::new(2).pow(100).to_string(), "6703205376");
+        assert_eq!(
+            Prime::new(3).pow(1_000),
+            Prime::new(mod_pow(3, 1_000, 1_000_000_007))
+        );
+    }
+
+    #[test]
+    fn division_inverts_multiplication() {
+        let value = Prime::new(123_456_789);
+
+        assert_eq!((value / Prime::new(42)) * Prime::new(42), value);
+        assert_eq!(
+            Prime::new(42).inverse().expect("42 to be invertible") * Prime::new(42),
+            Prime::new(1)
+        );
+        assert_eq!(LastTenDigits::new(5).inverse(), None);
+        assert_eq!(-Prime::new(1), Prime::new(1_000_000_006));
+        assert_eq!((1..=100).map(Prime::new).sum::<Prime>(), Prime::new(5_050));
+    }
+
+    #[test]
+    fn modulus_one_holds_only_zero() {
+        assert_eq!(ModInt::<1>::new(12).value(), 0);
+        assert_eq!((ModInt::<1>::new(3) * ModInt::<1>::new(4)).value(), 0);
+    }
+}
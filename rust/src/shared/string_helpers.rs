@@ -1,17 +1,47 @@
+use crate::shared::digits::{DigitIterator, Digits};
+use num::BigUint;
+
 /// Check if input string is a palindrome.
 /// - **input_string:** Input string to check if it is a palindrome.
 /// ## Notes
 /// - **Palindrome:** A word, phrase, or sequence that reads the same backward as forward.
 pub fn is_palindrome(input_string: &str) -> bool {
-    input_string == input_string.chars().rev().collect::<String>()
+    input_string.chars().eq(input_string.chars().rev())
+}
+
+/// Check if the digits of a primitive integer in a base form a palindrome.
+/// - **number:** Number to check if it is a palindrome.
+/// - **base:** Base of the digits, from 2 to 256.
+/// ## Notes
+/// - Digits are compared from both ends at once, so primitive integers are checked without
+///   allocating.
+pub fn is_palindrome_in_base<T: Digits + Copy>(number: T, base: u32) -> bool {
+    are_palindrome_digits(number.digits(base))
+}
+
+/// Check if the digits of an arbitrarily large integer in a base form a palindrome.
+/// - **number:** Number to check if it is a palindrome.
+/// - **base:** Base of the digits, from 2 to 256.
+pub fn is_palindrome_in_base_big(number: &BigUint, base: u32) -> bool {
+    are_palindrome_digits(number.digits(base))
+}
+
+/// Check if digits read the same from either end.
+/// - **digits:** Digits to check.
+fn are_palindrome_digits(mut digits: DigitIterator) -> bool {
+    while let (Some(low_digit), Some(high_digit)) = (digits.next(), digits.next_back()) {
+        if low_digit != high_digit {
+            return false;
+        }
+    }
+
+    true
 }
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
-    use super::is_palindrome;
-    use test::Bencher;
+    use super::{is_palindrome, is_palindrome_in_base, is_palindrome_in_base_big};
+    use num::BigUint;
 
     #[test]
     fn is_palindrome_returns_true_when_palindrome() {
@@ -20,13 +50,23 @@ mod tests {
 
     #[test]
     fn is_palindrome_returns_false_when_not_palindrome() {
-        assert!(is_palindrome("8989") == false);
+        assert!(!is_palindrome("8989"));
     }
 
-    #[bench]
-    fn bench_is_palindrome_using_one_hundred_character_string(bencher: &mut Bencher) {
-        let input_string = "8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008";
-
-        bencher.iter(|| is_palindrome(input_string));
+    #[test]
+    fn is_palindrome_in_base_checks_digits_in_base() {
+        assert!(is_palindrome_in_base(9_009_u64, 10));
+        assert!(!is_palindrome_in_base(8_989_u64, 10));
+        assert!(is_palindrome_in_base(585_u64, 2));
+        assert!(!is_palindrome_in_base(10_u64, 10));
+        assert!(is_palindrome_in_base(0_u64, 10));
+        assert!(is_palindrome_in_base(0xabba_u32, 16));
+        assert!(is_palindrome_in_base_big(
+            &"1234567890987654321001234567890987654321"
+                .parse::<BigUint>()
+                .expect("number to be parsable"),
+            10
+        ));
+        assert!(!is_palindrome_in_base_big(&BigUint::from(u128::MAX), 10));
     }
 }
@@ -2,12 +2,12 @@ use num::Integer;
 use std::fmt::Display;
 
 /// Ones place values.
-const ONES: [&str; 10] = [
+pub(super) const ONES: [&str; 10] = [
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
 /// Tens place values.
-const TENS: [&str; 18] = [
+pub(super) const TENS: [&str; 18] = [
     "ten",
     "eleven",
     "twelve",
@@ -29,7 +29,7 @@ const TENS: [&str; 18] = [
 ];
 
 /// Places values.
-const PLACES: [&str; 22] = [
+pub(super) const PLACES: [&str; 22] = [
     "hundred",
     "thousand",
     "million",
@@ -101,12 +101,9 @@ fn ones_place_english<'a>(
     ones_place_digit: &'a usize,
     tens_place_digit: Option<&'a usize>,
 ) -> &'a str {
-    if tens_place_digit.is_none() {
-        ONES[*ones_place_digit]
-    } else if *ones_place_digit != 0 && tens_place_digit.is_some_and(|digit| *digit != 1) {
-        ONES[*ones_place_digit]
-    } else {
-        ""
+    match tens_place_digit {
+        Some(tens_place_digit) if *ones_place_digit == 0 || *tens_place_digit == 1 => "",
+        _ => ONES[*ones_place_digit],
     }
 }
 
@@ -171,21 +168,21 @@ fn places_group_english(
         places_group_english = format!("{} {}", places_group_english, PLACES[places_group_index]);
     }
 
-    if tens_place_digit.is_some() {
+    if let Some(tens_place_digit) = tens_place_digit {
         places_group_english = format!(
             "{}{}",
-            tens_place_english(ones_place_digit, tens_place_digit.expect("to be some")),
+            tens_place_english(ones_place_digit, tens_place_digit),
             places_group_english
         );
     }
 
-    if hundreds_place_digit.is_some() {
+    if let Some(hundreds_place_digit) = hundreds_place_digit {
         places_group_english = format!(
             "{}{}",
             hundreds_place_english(
                 ones_place_digit,
                 tens_place_digit.expect("to be some since hundreds place digit is some"),
-                hundreds_place_digit.expect("to be some")
+                hundreds_place_digit
             ),
             places_group_english
         );
@@ -196,10 +193,7 @@ fn places_group_english(
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
     use super::ToEnglish;
-    use test::Bencher;
 
     #[test]
     fn to_english_returns_number_spelled_out_in_english() {
@@ -218,9 +212,4 @@ mod tests {
     fn to_english_handles_zero() {
         assert_eq!(0.to_english(), "zero");
     }
-
-    #[bench]
-    fn bench_to_english_using_maximum_u128(bencher: &mut Bencher) {
-        bencher.iter(|| u128::MAX.to_english());
-    }
 }
@@ -0,0 +1,113 @@
+mod solution_001;
+mod solution_002;
+mod solution_003;
+mod solution_004;
+mod solution_005;
+mod solution_006;
+mod solution_007;
+mod solution_008;
+mod solution_009;
+mod solution_010;
+mod solution_011;
+mod solution_012;
+mod solution_013;
+mod solution_014;
+mod solution_015;
+mod solution_016;
+mod solution_017;
+
+pub use solution_001::Solution001;
+pub use solution_002::Solution002;
+pub use solution_003::Solution003;
+pub use solution_004::Solution004;
+pub use solution_005::Solution005;
+pub use solution_006::Solution006;
+pub use solution_007::Solution007;
+pub use solution_008::Solution008;
+pub use solution_009::Solution009;
+pub use solution_010::Solution010;
+pub use solution_011::Solution011;
+pub use solution_012::Solution012;
+pub use solution_013::Solution013;
+pub use solution_014::Solution014;
+pub use solution_015::Solution015;
+pub use solution_016::Solution016;
+pub use solution_017::Solution017;
+
+use crate::{ProblemIndex, Solution};
+
+/// Registered solutions, ordered by problem index.
+static SOLUTIONS: [(ProblemIndex, &dyn Solution); 17] = [
+    (1, &Solution001 {}),
+    (2, &Solution002 {}),
+    (3, &Solution003 {}),
+    (4, &Solution004 {}),
+    (5, &Solution005 {}),
+    (6, &Solution006 {}),
+    (7, &Solution007 {}),
+    (8, &Solution008 {}),
+    (9, &Solution009 {}),
+    (10, &Solution010 {}),
+    (11, &Solution011 {}),
+    (12, &Solution012 {}),
+    (13, &Solution013 {}),
+    (14, &Solution014 {}),
+    (15, &Solution015 {}),
+    (16, &Solution016 {}),
+    (17, &Solution017 {}),
+];
+
+/// Get the solution registered for a problem.
+/// - **problem_index:** Problem index to get solution for.
+pub fn get_solution(problem_index: ProblemIndex) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|(index, _solution)| *index == problem_index)
+        .map(|(_index, solution)| *solution)
+}
+
+/// Get the problem indices of all registered solutions, in ascending order.
+pub fn problem_indices() -> impl Iterator<Item = ProblemIndex> {
+    SOLUTIONS.iter().map(|(index, _solution)| *index)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{get_solution, problem_indices};
+    use crate::{run_preset, Solution, EXAMPLE_PRESET, OFFICIAL_PRESET};
+
+    /// Assert solution solves its example preset with the expected answer.
+    /// - **solution:** Solution to run.
+    pub(crate) fn assert_solves_example(solution: &dyn Solution) {
+        let example = solution
+            .preset(EXAMPLE_PRESET)
+            .expect("solution to have an example preset");
+
+        assert_eq!(
+            run_preset(solution, EXAMPLE_PRESET).expect("solution to solve example"),
+            example.answer.expect("example preset to have an answer")
+        );
+    }
+
+    #[test]
+    fn get_solution_returns_none_for_unregistered_problem() {
+        assert!(get_solution(0).is_none());
+    }
+
+    #[test]
+    fn problem_indices_are_unique_and_ascending() {
+        let problem_indices: Vec<_> = problem_indices().collect();
+
+        assert!(problem_indices.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn registered_solutions_have_example_and_official_presets() {
+        for problem_index in problem_indices() {
+            let solution = get_solution(problem_index).expect("solution to be registered");
+
+            assert!(solution.preset(EXAMPLE_PRESET).is_ok());
+            assert!(solution.preset(OFFICIAL_PRESET).is_ok());
+        }
+    }
+}
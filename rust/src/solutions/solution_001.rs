@@ -0,0 +1,55 @@
+use crate::{Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument range threshold type.
+type RangeThreshold = u64;
+
+/// Command-line argument range threshold placeholder.
+const RANGE_THRESHOLD: &str = "RANGE_THRESHOLD";
+
+pub struct Solution001;
+impl Solution for Solution001 {
+    fn title(&self) -> String {
+        "Multiples of 3 or 5".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!("Sum of multiples of 3 or 5 below {}.", RANGE_THRESHOLD)
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(RANGE_THRESHOLD)
+            .help("Range threshold.")
+            .required(true)
+            .value_parser(value_parser!(RangeThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["10"], 23),
+            Preset::official(vec!["1000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let range_threshold = *arguments
+            .get_one::<RangeThreshold>(RANGE_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        Ok((0..range_threshold)
+            .filter(|n| n % 3 == 0 || n % 5 == 0)
+            .sum::<u64>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution001;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_001_example() {
+        assert_solves_example(&Solution001 {});
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{shared::iterators::FibonacciSequence, Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument sequence threshold type.
+type SequenceThreshold = u64;
+
+/// Command-line argument sequence threshold placeholder.
+const SEQUENCE_THRESHOLD: &str = "SEQUENCE_THRESHOLD";
+
+pub struct Solution002;
+impl Solution for Solution002 {
+    fn title(&self) -> String {
+        "Even Fibonacci numbers".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Sum of even Fibonacci sequence terms that do not exceed {}.",
+            SEQUENCE_THRESHOLD
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(SEQUENCE_THRESHOLD)
+            .help("Fibonacci sequence term threshold.")
+            .required(true)
+            .value_parser(value_parser!(SequenceThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["100"], 44),
+            Preset::official(vec!["4000000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let sequence_threshold = *arguments
+            .get_one::<SequenceThreshold>(SEQUENCE_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        let mut solution = 0;
+
+        for term in FibonacciSequence::default().filter(|term| term % 2 == 0) {
+            if term >= sequence_threshold {
+                break;
+            }
+
+            solution += term;
+        }
+
+        Ok(solution.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution002;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_002_example() {
+        assert_solves_example(&Solution002 {});
+    }
+}
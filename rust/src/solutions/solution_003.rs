@@ -0,0 +1,58 @@
+use crate::{shared::math_helpers::prime_factors, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument input number type.
+type InputNumber = u64;
+
+/// Command-line argument input number placeholder.
+const INPUT_NUMBER: &str = "INPUT_NUMBER";
+
+pub struct Solution003;
+impl Solution for Solution003 {
+    fn title(&self) -> String {
+        "Largest prime factor".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!("Largest prime factor of {}.", INPUT_NUMBER)
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(INPUT_NUMBER)
+            .help("Input number.")
+            .required(true)
+            .value_parser(value_parser!(InputNumber))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["13195"], 29),
+            Preset::official(vec!["600851475143"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let input_number = *arguments
+            .get_one::<InputNumber>(INPUT_NUMBER)
+            .expect("command-line arguments parser to get argument");
+
+        match prime_factors(input_number).last() {
+            Some(largest_prime_factor) => Ok(largest_prime_factor.base_value.into()),
+            None => Err(Error::invalid_argument(
+                INPUT_NUMBER,
+                "must have one or more prime factors",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution003;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_003_example() {
+        assert_solves_example(&Solution003 {});
+    }
+}
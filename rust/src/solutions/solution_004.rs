@@ -0,0 +1,77 @@
+use crate::{shared::iterators::Palindromes, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument digit count type.
+type DigitCount = usize;
+
+/// Command-line argument digit count placeholder.
+const DIGIT_COUNT: &str = "DIGIT_COUNT";
+
+pub struct Solution004;
+impl Solution for Solution004 {
+    fn title(&self) -> String {
+        "Largest palindrome product".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Largest palindrome product from two numbers of {}.",
+            DIGIT_COUNT
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(DIGIT_COUNT)
+            .help("Number of digits that make up the two numbers to multiply.")
+            .required(true)
+            .value_parser(value_parser!(DigitCount))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["2"], 9009),
+            Preset::official(vec!["3"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let digit_count = *arguments
+            .get_one::<DigitCount>(DIGIT_COUNT)
+            .expect("command-line arguments parser to get argument");
+
+        if !(1..=9).contains(&digit_count) {
+            return Err(Error::invalid_argument(
+                DIGIT_COUNT,
+                "must be between 1 and 9",
+            ));
+        }
+
+        let start = 10_u64.pow(digit_count as u32 - 1);
+        let end = (start * 10) - 1;
+        let has_factor_pair = |palindrome: u64| {
+            (start..=end)
+                .rev()
+                .take_while(|factor| factor * factor >= palindrome)
+                .any(|factor| palindrome.is_multiple_of(factor) && palindrome / factor >= start)
+        };
+
+        let product_digit_count = 2 * digit_count as u32;
+        Palindromes::new(product_digit_count, 10)
+            .rev()
+            .chain(Palindromes::new(product_digit_count - 1, 10).rev())
+            .find(|&palindrome| has_factor_pair(palindrome))
+            .map(Answer::from)
+            .ok_or_else(|| Error::invalid_argument(DIGIT_COUNT, "has no palindrome product"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution004;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_004_example() {
+        assert_solves_example(&Solution004 {});
+    }
+}
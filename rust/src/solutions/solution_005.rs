@@ -0,0 +1,61 @@
+use crate::{shared::Factorization, Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument maximum multiple type.
+type MaximumMultiple = u64;
+
+/// Command-line argument maximum multiple placeholder.
+const MAXIMUM_MULTIPLE: &str = "MAXIMUM_MULTIPLE";
+
+pub struct Solution005;
+impl Solution for Solution005 {
+    fn title(&self) -> String {
+        "Smallest multiple".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Smallest number that is evenly divisible by all numbers from 1 to {}.",
+            MAXIMUM_MULTIPLE
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(MAXIMUM_MULTIPLE)
+            .help("Maximum multiple to be checked.")
+            .required(true)
+            .value_parser(value_parser!(MaximumMultiple))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["10"], 2520),
+            Preset::official(vec!["20"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let maximum_multiple = *arguments
+            .get_one::<MaximumMultiple>(MAXIMUM_MULTIPLE)
+            .expect("command-line arguments parser to get argument");
+
+        let solution = (1..=maximum_multiple)
+            .map(Factorization::new)
+            .fold(Factorization::default(), |multiple, factorization| {
+                multiple.lcm(&factorization)
+            });
+
+        Ok(solution.to_biguint().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution005;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_005_example() {
+        assert_solves_example(&Solution005 {});
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument range threshold type.
+type RangeThreshold = u64;
+
+/// Command-line argument range threshold placeholder.
+const RANGE_THRESHOLD: &str = "RANGE_THRESHOLD";
+
+pub struct Solution006;
+impl Solution for Solution006 {
+    fn title(&self) -> String {
+        "Sum square difference".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Difference between sum of squares and square of sums up to {}.",
+            RANGE_THRESHOLD
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(RANGE_THRESHOLD)
+            .help("Range threshold.")
+            .required(true)
+            .value_parser(value_parser!(RangeThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["10"], 2640),
+            Preset::official(vec!["100"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let range_threshold = *arguments
+            .get_one::<RangeThreshold>(RANGE_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        let mut sum_of_squares = 0;
+        let mut square_of_sums = 0;
+
+        for i in 1..=range_threshold {
+            sum_of_squares += i * i;
+            square_of_sums += i;
+        }
+
+        Ok(((square_of_sums * square_of_sums) - sum_of_squares).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution006;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_006_example() {
+        assert_solves_example(&Solution006 {});
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{shared::iterators::PrimeNumbers, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument prime index type.
+type PrimeIndex = usize;
+
+/// Command-line argument prime index placeholder.
+const PRIME_INDEX: &str = "PRIME_INDEX";
+
+pub struct Solution007;
+impl Solution for Solution007 {
+    fn title(&self) -> String {
+        "10001st prime".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!("Get the Nth prime number. Where N is {}.", PRIME_INDEX)
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(PRIME_INDEX)
+            .help("Nth prime number to be returned.")
+            .required(true)
+            .value_parser(value_parser!(PrimeIndex))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["6"], 13),
+            Preset::official(vec!["10001"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let prime_index = *arguments
+            .get_one::<PrimeIndex>(PRIME_INDEX)
+            .expect("command-line arguments parser to get argument");
+
+        match PrimeNumbers::default().take(prime_index).last() {
+            Some(prime_number) => Ok(prime_number.into()),
+            None => Err(Error::invalid_argument(
+                PRIME_INDEX,
+                "must be greater than 0",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution007;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_007_example() {
+        assert_solves_example(&Solution007 {});
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{shared::file_helpers::read_to_vector, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument series file path type.
+type SeriesFilePath = String;
+
+/// Command-line argument adjacent digit count type.
+type AdjacentDigitCount = u64;
+
+/// Command-line argument series file path placeholder.
+const SERIES_FILE_PATH: &str = "SERIES_FILE_PATH";
+
+/// Command-line argument adjacent digit count placeholder.
+const ADJACENT_DIGIT_COUNT: &str = "ADJACENT_DIGIT_COUNT";
+
+pub struct Solution008;
+impl Solution for Solution008 {
+    fn title(&self) -> String {
+        "Largest product in a series".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Largest product made up of {} in series located in {}.",
+            ADJACENT_DIGIT_COUNT, SERIES_FILE_PATH
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![
+            Arg::new(SERIES_FILE_PATH)
+                .help("File path to input series.")
+                .required(true)
+                .value_parser(value_parser!(SeriesFilePath)),
+            Arg::new(ADJACENT_DIGIT_COUNT)
+                .help("Number of adjacent digits to multiply.")
+                .required(true)
+                .value_parser(value_parser!(AdjacentDigitCount)),
+        ]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec![".test_input/008.dat", "2"], 72),
+            Preset::official(vec![".problem_input/008.dat", "13"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let adjacent_digit_count = *arguments
+            .get_one::<AdjacentDigitCount>(ADJACENT_DIGIT_COUNT)
+            .expect("command-line arguments parser to get argument");
+        let series_file_path = arguments
+            .get_one::<SeriesFilePath>(SERIES_FILE_PATH)
+            .expect("command-line arguments parser to get argument");
+
+        let input_data = read_to_vector::<u64>(series_file_path, None)?;
+        let mut solution = 0;
+
+        if (input_data.len() as AdjacentDigitCount) < adjacent_digit_count {
+            return Err(Error::invalid_argument(
+                ADJACENT_DIGIT_COUNT,
+                format!(
+                    "must not exceed the {} digits in {}",
+                    input_data.len(),
+                    series_file_path
+                ),
+            ));
+        }
+
+        for i in 0..=(input_data.len() as AdjacentDigitCount - adjacent_digit_count) {
+            let mut product = 1;
+
+            for offset in 0..adjacent_digit_count {
+                product *= input_data[(i + offset) as usize];
+            }
+
+            solution = if product > solution {
+                product
+            } else {
+                solution
+            };
+        }
+
+        Ok(solution.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution008;
+    use crate::{solutions::tests::assert_solves_example, Error, Solution};
+    use clap::command;
+
+    const APPLICATION_NAME: &str = "test_app";
+
+    #[test]
+    fn solves_problem_008_example() {
+        assert_solves_example(&Solution008 {});
+    }
+
+    #[test]
+    fn rejects_problem_008_when_series_is_shorter_than_adjacent_digit_count() {
+        let solution = Solution008 {};
+
+        let arguments = command!().args(solution.arguments()).get_matches_from(vec![
+            APPLICATION_NAME,
+            ".test_input/008.dat",
+            "9",
+        ]);
+
+        assert!(matches!(
+            solution.run(&arguments),
+            Err(Error::InvalidArgument { .. })
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    shared::iterators::{PythagoreanTriples, TripleBound},
+    Answer, Error, Preset, Result, Solution,
+};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument target triplet sum type.
+type TargetTripletSum = u64;
+
+/// Command-line argument target triplet sum placeholder.
+const TARGET_TRIPLET_SUM: &str = "TARGET_TRIPLET_SUM";
+
+pub struct Solution009;
+impl Solution for Solution009 {
+    fn title(&self) -> String {
+        "Special Pythagorean triplet".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Product of a * b * c when a + b + c equals {}.",
+            TARGET_TRIPLET_SUM
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(TARGET_TRIPLET_SUM)
+            .help("Target pythagorean triplet sum.")
+            .required(true)
+            .value_parser(value_parser!(TargetTripletSum))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["12"], 60),
+            Preset::official(vec!["1000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let target_triplet_sum = *arguments
+            .get_one::<TargetTripletSum>(TARGET_TRIPLET_SUM)
+            .expect("command-line arguments parser to get argument");
+
+        match PythagoreanTriples::with_multiples(TripleBound::Perimeter(target_triplet_sum))
+            .find(|triple| triple.perimeter() == target_triplet_sum)
+        {
+            Some(triple) => Ok((triple.a * triple.b * triple.c).into()),
+            None => Err(Error::invalid_argument(
+                TARGET_TRIPLET_SUM,
+                "must be the sum of a pythagorean triplet",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution009;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_009_example() {
+        assert_solves_example(&Solution009 {});
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{shared::math_helpers::sieve::primes_below, Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument range threshold type.
+type RangeThreshold = u32;
+
+/// Command-line argument range threshold placeholder.
+const RANGE_THRESHOLD: &str = "RANGE_THRESHOLD";
+
+pub struct Solution010;
+impl Solution for Solution010 {
+    fn title(&self) -> String {
+        "Smallest multiple".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!("Sum of primes below {}.", RANGE_THRESHOLD)
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(RANGE_THRESHOLD)
+            .help("Range threshold.")
+            .required(true)
+            .value_parser(value_parser!(RangeThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["10"], 17),
+            Preset::official(vec!["2000000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let range_threshold = *arguments
+            .get_one::<RangeThreshold>(RANGE_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        Ok(primes_below(range_threshold.into())
+            .into_iter()
+            .sum::<u64>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution010;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_010_example() {
+        assert_solves_example(&Solution010 {});
+    }
+}
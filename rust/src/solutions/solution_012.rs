@@ -0,0 +1,71 @@
+use crate::{shared::Factorization, Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument divisor count threshold type.
+type DivisorCountThreshold = u64;
+
+/// Command-line argument divisor count threshold placeholder.
+const DIVISOR_COUNT_THRESHOLD: &str = "DIVISOR_COUNT_THRESHOLD";
+
+pub struct Solution012;
+impl Solution for Solution012 {
+    fn title(&self) -> String {
+        "Highly divisible triangular number".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "First triangle number to have over {} divisors.",
+            DIVISOR_COUNT_THRESHOLD
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(DIVISOR_COUNT_THRESHOLD)
+            .help("Divisor count threshold.")
+            .required(true)
+            .value_parser(value_parser!(DivisorCountThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["5"], 28),
+            Preset::official(vec!["500"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let divisor_count_threshold = *arguments
+            .get_one::<DivisorCountThreshold>(DIVISOR_COUNT_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        let two = Factorization::new(2);
+        let mut index = 1;
+        let mut factorization = Factorization::new(index);
+
+        loop {
+            let next_factorization = Factorization::new(index + 1);
+            let triangle_number = (factorization * next_factorization.clone())
+                .checked_div(&two)
+                .expect("product of consecutive numbers to be even");
+
+            if triangle_number.divisor_count() > divisor_count_threshold {
+                break Ok(triangle_number.to_biguint().into());
+            }
+
+            factorization = next_factorization;
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution012;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_012_example() {
+        assert_solves_example(&Solution012 {});
+    }
+}
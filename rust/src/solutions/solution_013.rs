@@ -0,0 +1,101 @@
+use crate::{shared::file_helpers::read_to_vector, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+use num::BigUint;
+
+/// Command-line argument file path type.
+type FilePath = String;
+
+/// Command-line argument digit count type.
+type DigitCount = usize;
+
+/// Command-line argument file path placeholder.
+const FILE_PATH: &str = "FILE_PATH";
+
+/// Command-line argument digit count placeholder.
+const DIGIT_COUNT: &str = "DIGIT_COUNT";
+
+pub struct Solution013;
+impl Solution for Solution013 {
+    fn title(&self) -> String {
+        "Large sum".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "First {} digits of the sum of numbers located in {}.",
+            DIGIT_COUNT, FILE_PATH
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![
+            Arg::new(FILE_PATH)
+                .help("File path to input series.")
+                .required(true)
+                .value_parser(value_parser!(FilePath)),
+            Arg::new(DIGIT_COUNT)
+                .help("Number of digits.")
+                .required(true)
+                .value_parser(value_parser!(DigitCount)),
+        ]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec![".test_input/013.dat", "2"], "27"),
+            Preset::official(vec![".problem_input/013.dat", "10"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let file_path = arguments
+            .get_one::<FilePath>(FILE_PATH)
+            .expect("command-line arguments parser to get argument");
+        let digit_count = arguments
+            .get_one::<DigitCount>(DIGIT_COUNT)
+            .expect("command-line arguments parser to get argument");
+
+        let sum = read_to_vector::<BigUint>(file_path, Some(&'\n'))?
+            .iter()
+            .sum::<BigUint>()
+            .to_string();
+
+        match sum.get(0..*digit_count) {
+            Some(first_digits) => Ok(first_digits.into()),
+            None => Err(Error::invalid_argument(
+                DIGIT_COUNT,
+                format!("must not exceed the {} digits of the sum", sum.len()),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution013;
+    use crate::{solutions::tests::assert_solves_example, Error, Solution};
+    use clap::command;
+
+    const APPLICATION_NAME: &str = "test_app";
+
+    #[test]
+    fn solves_problem_013_example() {
+        assert_solves_example(&Solution013 {});
+    }
+
+    #[test]
+    fn rejects_problem_013_when_digit_count_exceeds_digits_of_sum() {
+        let solution = Solution013 {};
+
+        let arguments = command!().args(solution.arguments()).get_matches_from(vec![
+            APPLICATION_NAME,
+            ".test_input/013.dat",
+            "30",
+        ]);
+
+        assert!(matches!(
+            solution.run(&arguments),
+            Err(Error::InvalidArgument { .. })
+        ));
+    }
+}
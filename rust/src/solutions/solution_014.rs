@@ -0,0 +1,70 @@
+use crate::{shared::collatz::CollatzCache, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument start number threshold type.
+type StartNumberThreshold = u64;
+
+/// Command-line argument start number threshold placeholder.
+const START_NUMBER_THRESHOLD: &str = "START_NUMBER_THRESHOLD";
+
+pub struct Solution014;
+impl Solution for Solution014 {
+    fn title(&self) -> String {
+        "Longest Collatz sequence".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Start number of the longest Collatz sequence chain below {}.",
+            START_NUMBER_THRESHOLD
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(START_NUMBER_THRESHOLD)
+            .help("Start number threshold.")
+            .required(true)
+            .value_parser(value_parser!(StartNumberThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["10"], 9),
+            Preset::official(vec!["1000000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let start_number_threshold = *arguments
+            .get_one::<StartNumberThreshold>(START_NUMBER_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        if start_number_threshold <= 2 {
+            return Err(Error::invalid_argument(
+                START_NUMBER_THRESHOLD,
+                "must be greater than 2",
+            ));
+        }
+
+        let collatz_cache =
+            CollatzCache::new(usize::try_from(start_number_threshold).unwrap_or(usize::MAX));
+
+        collatz_cache
+            .longest_chain_below(start_number_threshold)
+            .map(|longest_chain| longest_chain.start_number.into())
+            .ok_or_else(|| {
+                Error::invalid_argument(START_NUMBER_THRESHOLD, "must be greater than 2")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution014;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_014_example() {
+        assert_solves_example(&Solution014 {});
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{shared::combinatorics::binomial_big, Answer, Error, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+
+/// Command-line argument range threshold type.
+type GridSize = u64;
+
+/// Command-line argument range threshold placeholder.
+const GRID_SIZE: &str = "GRID_SIZE";
+
+pub struct Solution015;
+impl Solution for Solution015 {
+    fn title(&self) -> String {
+        "Lattice paths".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Number of routes through a {} by {} grid.",
+            GRID_SIZE, GRID_SIZE
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(GRID_SIZE)
+            .help("Width and height of the grid.")
+            .required(true)
+            .value_parser(value_parser!(GridSize))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![Preset::example(vec!["2"], 6), Preset::official(vec!["20"])]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let grid_size = *arguments
+            .get_one::<GridSize>(GRID_SIZE)
+            .expect("command-line arguments parser to get argument");
+
+        let path_length = grid_size.checked_mul(2).ok_or_else(|| {
+            Error::invalid_argument(GRID_SIZE, "must be at most half of u64::MAX")
+        })?;
+
+        Ok(binomial_big(path_length, grid_size).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution015;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_015_example() {
+        assert_solves_example(&Solution015 {});
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{shared::digits::Digits, Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+use num::BigUint;
+
+/// Command-line argument range threshold type.
+type ExponentPower = u32;
+
+/// Command-line argument range threshold placeholder.
+const EXPONENT_POWER: &str = "EXPONENT_POWER";
+
+pub struct Solution016;
+impl Solution for Solution016 {
+    fn title(&self) -> String {
+        "Power digit sum".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Sum of the digits from the result of 2 raised to the power of {}.",
+            EXPONENT_POWER
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(EXPONENT_POWER)
+            .help("Power to raise 2 by.")
+            .required(true)
+            .value_parser(value_parser!(ExponentPower))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["15"], 26),
+            Preset::official(vec!["1000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        let exponent_power = *arguments
+            .get_one::<ExponentPower>(EXPONENT_POWER)
+            .expect("command-line arguments parser to get argument");
+
+        Ok(BigUint::from(2_u8).pow(exponent_power).digit_sum(10).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution016;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_016_example() {
+        assert_solves_example(&Solution016 {});
+    }
+}
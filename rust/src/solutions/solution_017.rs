@@ -0,0 +1,76 @@
+use crate::{shared::ToEnglish, Answer, Preset, Result, Solution};
+use clap::{value_parser, Arg, ArgMatches};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// Command-line argument range threshold type.
+type RangeThreshold = u64;
+
+/// Command-line argument range threshold placeholder.
+const RANGE_THRESHOLD: &str = "RANGE_THRESHOLD";
+
+pub struct Solution017;
+impl Solution for Solution017 {
+    fn title(&self) -> String {
+        "Number letter counts".to_string()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Number of letters when integers from 1 to {} are written out in words.",
+            RANGE_THRESHOLD
+        )
+    }
+
+    fn arguments(&self) -> Vec<Arg> {
+        vec![Arg::new(RANGE_THRESHOLD)
+            .help("Largest integer to be written out in words.")
+            .required(true)
+            .value_parser(value_parser!(RangeThreshold))]
+    }
+
+    fn presets(&self) -> Vec<Preset> {
+        vec![
+            Preset::example(vec!["5"], 19),
+            Preset::official(vec!["1000"]),
+        ]
+    }
+
+    fn run(&self, arguments: &ArgMatches) -> Result<Answer> {
+        lazy_static! {
+            static ref ALPHA_REGEX: Regex = Regex::new(r"[a-z]+").expect("RegEx to be valid");
+        }
+
+        let range_threshold = *arguments
+            .get_one::<RangeThreshold>(RANGE_THRESHOLD)
+            .expect("command-line arguments parser to get argument");
+
+        Ok((1..=range_threshold)
+            .fold(0, |sum, i| {
+                let index_english = i.to_english();
+
+                let letter_count =
+                    ALPHA_REGEX
+                        .captures_iter(&index_english)
+                        .fold(0, |sum, captures| {
+                            sum + captures
+                                .iter()
+                                .fold(0, |sum, capture| sum + capture.unwrap().as_str().len())
+                        }) as u64;
+
+                sum + letter_count
+            })
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution017;
+    use crate::solutions::tests::assert_solves_example;
+
+    #[test]
+    fn solves_problem_017_example() {
+        assert_solves_example(&Solution017 {});
+    }
+}
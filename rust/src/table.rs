@@ -0,0 +1,38 @@
+/// Format rows as a plain text table with a header row, padding each column to its widest cell.
+/// - **headers:** Column headers.
+/// - **rows:** Table rows, each with one cell per column.
+pub(crate) fn format_table<const COLUMNS: usize>(
+    headers: [&str; COLUMNS],
+    rows: &[[String; COLUMNS]],
+) -> String {
+    let mut column_widths = headers.map(|header| header.chars().count());
+    for row in rows {
+        for (column_width, cell) in column_widths.iter_mut().zip(row) {
+            *column_width = (*column_width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[&str]| {
+        cells
+            .iter()
+            .zip(column_widths)
+            .map(|(cell, column_width)| format!("{:<column_width$}", cell))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = vec![
+        format_row(&headers),
+        column_widths
+            .map(|column_width| "-".repeat(column_width))
+            .join("-|-"),
+    ];
+    table.extend(
+        rows.iter()
+            .map(|row| format_row(&row.each_ref().map(String::as_str))),
+    );
+
+    table.join("\n")
+}